@@ -1,7 +1,16 @@
 use anyhow::{Context, Result};
 use clap::Parser;
 use notion_generator::{
-    client::NotionClient, download::Downloadables, options::HeadingAnchors, response::NotionId,
+    client::NotionClient,
+    download::Downloadables,
+    highlight::LanguageRegistry,
+    options::{
+        DatabaseDisplay, HeadingAnchors, TemplateMentions, TemplateUser, UnsupportedBlocks,
+        UnsupportedLanguages, UserMentions,
+    },
+    response::NotionId,
+    unfurl::UnfurledLinks,
+    warnings::Warnings,
     HtmlRenderer,
 };
 use std::{
@@ -88,6 +97,40 @@ struct Opts {
     /// 46ce88507ab748c78f92024dc1190ca7:/path/to/page,9b4d1ba2963e4dd885fc9c3c4284fc74:/path/to/other/page
     #[clap(long)]
     link_map: LinkMap,
+    /// Render databases inside of the page as tables of their pages' properties instead of
+    /// lists of links to their pages
+    #[clap(long)]
+    database_tables: bool,
+    /// What to do with blocks that aren't supported: leave them out of the page, render a
+    /// placeholder in their place or fail
+    #[clap(long, possible_values = ["skip", "placeholder", "fail"], default_value = "placeholder")]
+    unsupported_blocks: String,
+    /// Render the avatars of mentioned users next to their names
+    #[clap(long)]
+    user_avatars: bool,
+    /// Don't link the names of mentioned users to their emails
+    #[clap(long)]
+    no_user_emails: bool,
+    /// The name of the user to render `@Me` mentions left over from templates as
+    #[clap(long)]
+    template_user_name: Option<String>,
+    /// The email of the user to render `@Me` mentions left over from templates as
+    #[clap(long, requires = "template-user-name")]
+    template_user_email: Option<String>,
+    /// Fetch the pages that link previews and bookmarks point to, to render them with their titles
+    /// and icons
+    #[clap(long)]
+    unfurl_links: bool,
+    /// A JSON file to cache unfurled links in between runs
+    #[clap(long, requires = "unfurl-links")]
+    unfurl_cache: Option<PathBuf>,
+    /// Render the name of the language above code blocks
+    #[clap(long)]
+    code_language_labels: bool,
+    /// What to do with code blocks in languages that can't be highlighted: render them as plain
+    /// text or fail
+    #[clap(long, possible_values = ["plain-text", "fail"], default_value = "plain-text")]
+    unsupported_languages: String,
 }
 
 #[tokio::main]
@@ -142,16 +185,63 @@ async fn main() -> Result<()> {
     } else {
         HeadingAnchors::None
     };
+    let mut unfurled_links = match &opts.unfurl_cache {
+        Some(cache) => UnfurledLinks::load(cache).await?,
+        None => UnfurledLinks::new(),
+    };
+    if opts.unfurl_links {
+        unfurled_links.unfurl_blocks(&reqwest_client, &blocks).await;
+
+        if let Some(cache) = &opts.unfurl_cache {
+            unfurled_links.save(cache).await?;
+        }
+    }
+
     let downloadables = Downloadables::new();
+    let warnings = Warnings::new();
+    let languages = LanguageRegistry::default();
     let renderer = HtmlRenderer {
         heading_anchors,
         current_pages,
         link_map: &opts.link_map.0,
         downloadables: &downloadables,
+        child_databases: if opts.database_tables {
+            DatabaseDisplay::Table
+        } else {
+            DatabaseDisplay::List
+        },
+        unsupported_blocks: match opts.unsupported_blocks.as_str() {
+            "skip" => UnsupportedBlocks::Skip,
+            "fail" => UnsupportedBlocks::Fail,
+            _ => UnsupportedBlocks::Placeholder,
+        },
+        user_mentions: UserMentions {
+            avatars: opts.user_avatars,
+            email_links: !opts.no_user_emails,
+        },
+        template_mentions: TemplateMentions {
+            me: opts.template_user_name.map(|name| TemplateUser {
+                name,
+                avatar_url: None,
+                email: opts.template_user_email,
+            }),
+            ..Default::default()
+        },
+        unfurled_links: &unfurled_links,
+        code_language_labels: opts.code_language_labels,
+        languages: &languages,
+        unsupported_languages: match opts.unsupported_languages.as_str() {
+            "fail" => UnsupportedLanguages::Fail,
+            _ => UnsupportedLanguages::PlainText,
+        },
+        warnings: &warnings,
     };
     let markup = renderer
         .render_html(blocks, head)
         .context("Failed to render page")?;
+    for warning in warnings.into_vec() {
+        eprintln!("WARNING: {}", warning);
+    }
 
     let write_markup = async {
         tokio::fs::write(opts.output.join("index.html"), markup.0)
@@ -1,4 +1,7 @@
-use crate::response::{Block, Error, List, NotionId, Page};
+use crate::response::{
+    properties::AnyProperties, Ancestor, Block, BlockParent, BlockType, Database, Error, ErrorCode,
+    List, NotionId, Page, PageParent, PageReference, PlainText, SyncedFrom,
+};
 use anyhow::{bail, format_err, Context, Result};
 use futures_util::stream::{FuturesOrdered, TryStreamExt};
 use reqwest::{Client, Method, Request};
@@ -6,9 +9,13 @@ use serde::{Deserialize, Serialize};
 use std::{future::Future, ops::Not, pin::Pin};
 use tower::{buffer::Buffer, limit::RateLimit, Service, ServiceExt};
 
+const NOTION_API_URL: &str = "https://api.notion.com/v1";
+
 pub struct NotionClient {
     svc: Buffer<RateLimit<Client>, Request>,
     auth_token: String,
+    /// The URL that the paths of the API's endpoints are appended to
+    api_url: String,
 }
 
 mod request {
@@ -108,6 +115,7 @@ impl NotionClient {
         NotionClient {
             svc: Self::make_service(Client::new()),
             auth_token,
+            api_url: NOTION_API_URL.to_string(),
         }
     }
 
@@ -115,6 +123,7 @@ impl NotionClient {
         NotionClient {
             svc: Self::make_service(client),
             auth_token,
+            api_url: NOTION_API_URL.to_string(),
         }
     }
 
@@ -145,16 +154,7 @@ impl NotionClient {
                 .await
                 .with_context(|| format!("Failed to parse error for request {}", url))?;
 
-            bail!(
-                "{}: {}",
-                serde_json::to_value(error.code)
-                    .expect("unreachable")
-                    .as_str()
-                    .context(
-                        "Error code should was not a JSON string? This should be unreachable"
-                    )?,
-                error.message
-            );
+            return Err(error.into());
         }
 
         let parsed = response
@@ -165,16 +165,27 @@ impl NotionClient {
         Ok(parsed)
     }
 
-    pub fn get_block_children<'a>(
-        &'a self,
+    pub fn get_block_children(
+        &self,
+        id: NotionId,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Block>>> + '_>> {
+        self.get_block_children_inside(id, None, Vec::new())
+    }
+
+    /// Get the children of a block that is nested inside of the synced blocks in `synced_blocks`,
+    /// with `copy` being the outermost copy of a synced block that it's shown through if there's one
+    fn get_block_children_inside(
+        &self,
         id: NotionId,
-    ) -> Pin<Box<dyn Future<Output = Result<Vec<Block>>> + 'a>> {
+        copy: Option<NotionId>,
+        synced_blocks: Vec<NotionId>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<Block>>> + '_>> {
         let future = async move {
             let mut cursor = None;
             let mut output = FuturesOrdered::new();
 
             loop {
-                let url = format!("https://api.notion.com/v1/blocks/{}/children", id);
+                let url = format!("{}/blocks/{}/children", self.api_url, id);
                 let list = self
                     .send_request::<List<Block>>(
                         &url,
@@ -188,14 +199,100 @@ impl NotionClient {
                 let requests = list
                     .results
                     .into_iter()
-                    .map(|block| async {
-                        if !block.has_children {
-                            return Ok::<Block, anyhow::Error>(block);
+                    .map(|mut block| {
+                        let mut synced_blocks = synced_blocks.clone();
+
+                        async move {
+                            let content = content_source(&block, &mut synced_blocks)?;
+
+                            match &mut block.ty {
+                                BlockType::LinkToPage { target, title } => {
+                                    match self.get_page_reference_title(target).await {
+                                        Ok(target_title) => *title = target_title,
+                                        // Pages and databases that aren't shared with the
+                                        // integration can't be linked to, so they are left to the
+                                        // unsupported blocks policy instead
+                                        Err(error) if is_inaccessible(&error) => {
+                                            let raw = match target {
+                                                PageReference::Page { id } => serde_json::json!({
+                                                    "type": "page_id",
+                                                    "page_id": id.to_string(),
+                                                }),
+                                                PageReference::Database { id } => {
+                                                    serde_json::json!({
+                                                        "type": "database_id",
+                                                        "database_id": id.to_string(),
+                                                    })
+                                                }
+                                            };
+                                            block.ty = BlockType::Unsupported {
+                                                ty: "link_to_page".to_string(),
+                                                raw,
+                                            };
+                                        }
+                                        Err(error) => return Err(error),
+                                    }
+                                }
+                                BlockType::ChildDatabase { title, pages } => {
+                                    // The id of a child database block is the id of the database
+                                    match self.query_database(&block.id.to_string()).await {
+                                        Ok(database_pages) => *pages = database_pages,
+                                        // Linked databases and databases that aren't shared with
+                                        // the integration can't be queried, so they are left to
+                                        // the unsupported blocks policy instead
+                                        Err(error) if is_inaccessible(&error) => {
+                                            block.ty = BlockType::Unsupported {
+                                                ty: "child_database".to_string(),
+                                                raw: serde_json::json!({ "title": title }),
+                                            };
+                                        }
+                                        Err(error) => return Err(error),
+                                    }
+                                }
+                                BlockType::SyncedBlock {
+                                    synced_from: Some(_),
+                                    children,
+                                } => {
+                                    *children = self
+                                        .get_block_children_inside(
+                                            content,
+                                            copy.or(Some(block.id)),
+                                            synced_blocks,
+                                        )
+                                        .await?;
+
+                                    return Ok(block);
+                                }
+                                BlockType::Breadcrumb { trail } => {
+                                    // The content of synced block copies is inside of the original,
+                                    // so the trail is of where the copy is instead
+                                    *trail = self
+                                        .get_ancestors_of(PageParent::Block {
+                                            id: copy.unwrap_or(block.id),
+                                        })
+                                        .await?;
+                                }
+                                _ => {}
+                            }
+
+                            // Child pages report having children but those are the content of the
+                            // child page itself and not something rendered inside the current page
+                            // Unsupported blocks have nowhere to keep their children either
+                            if !block.has_children
+                                || matches!(
+                                    block.ty,
+                                    BlockType::ChildPage { .. } | BlockType::Unsupported { .. }
+                                )
+                            {
+                                return Ok::<Block, anyhow::Error>(block);
+                            }
+
+                            let children = self
+                                .get_block_children_inside(content, copy, synced_blocks)
+                                .await?;
+
+                            Ok(block.replace_children(children))
                         }
-
-                        let children = self.get_block_children(block.id).await?;
-
-                        Ok(block.replace_children(children))
                     })
                     .collect::<Vec<_>>();
 
@@ -212,7 +309,108 @@ impl NotionClient {
         Box::pin(future)
     }
 
-    pub async fn get_database_pages<P>(&self, id: &str) -> Result<Vec<Page<P>>>
+    pub async fn get_page<P>(&self, id: NotionId) -> Result<Page<P>>
+    where
+        P: for<'de> Deserialize<'de>,
+    {
+        let url = format!("{}/pages/{}", self.api_url, id);
+        self.send_request(&url, self.build_request(Method::GET, &url)?)
+            .await
+    }
+
+    pub async fn get_database(&self, id: NotionId) -> Result<Database> {
+        let url = format!("{}/databases/{}", self.api_url, id);
+        self.send_request(&url, self.build_request(Method::GET, &url)?)
+            .await
+    }
+
+    pub async fn get_block_parent(&self, id: NotionId) -> Result<PageParent> {
+        let url = format!("{}/blocks/{}", self.api_url, id);
+        let block = self
+            .send_request::<BlockParent>(&url, self.build_request(Method::GET, &url)?)
+            .await?;
+
+        Ok(block.parent)
+    }
+
+    /// Get the pages and databases a page is inside of, from the top most one down to the page
+    /// itself
+    ///
+    /// Integrations are usually only shared with some of the pages of a workspace, so the ancestors
+    /// start from the top most one that is shared with the integration
+    pub async fn get_ancestors(&self, page: NotionId) -> Result<Vec<Ancestor>> {
+        self.get_ancestors_of(PageParent::Page {
+            id: page.to_string(),
+        })
+        .await
+    }
+
+    /// Get the pages and databases that are above `parent`, including itself if it's a page or a
+    /// database, from the top most one down
+    async fn get_ancestors_of(&self, mut parent: PageParent) -> Result<Vec<Ancestor>> {
+        let mut ancestors = Vec::new();
+
+        while parent != PageParent::Workspace {
+            parent = match self.get_ancestor(parent, &mut ancestors).await {
+                Ok(parent) => parent,
+                Err(error) if is_inaccessible(&error) => break,
+                Err(error) => return Err(error),
+            };
+        }
+
+        ancestors.reverse();
+        Ok(ancestors)
+    }
+
+    /// Push the page or database `parent` onto `ancestors` and get its own parent
+    async fn get_ancestor(
+        &self,
+        parent: PageParent,
+        ancestors: &mut Vec<Ancestor>,
+    ) -> Result<PageParent> {
+        match parent {
+            PageParent::Page { id } => {
+                let id = id.parse()?;
+                let page = self.get_page::<AnyProperties>(id).await?;
+                ancestors.push(Ancestor {
+                    id,
+                    title: page.properties.into_title().plain_text(),
+                });
+
+                Ok(page.parent)
+            }
+            PageParent::Database { id } => {
+                let id = id.parse()?;
+                let database = self.get_database(id).await?;
+                ancestors.push(Ancestor {
+                    id,
+                    title: database.title.plain_text(),
+                });
+
+                Ok(database.parent)
+            }
+            // Pages can be nested inside of blocks such as columns of another page, those blocks
+            // aren't part of the trail but the page they are in is
+            PageParent::Block { id } => self.get_block_parent(id).await,
+            PageParent::Workspace => Ok(PageParent::Workspace),
+        }
+    }
+
+    async fn get_page_reference_title(&self, reference: &PageReference) -> Result<String> {
+        let title = match reference {
+            PageReference::Page { id } => self
+                .get_page::<AnyProperties>(*id)
+                .await?
+                .properties
+                .into_title(),
+            PageReference::Database { id } => self.get_database(*id).await?.title,
+        };
+
+        Ok(title.plain_text())
+    }
+
+    /// Get the pages of a database without their content
+    pub async fn query_database<P>(&self, id: &str) -> Result<Vec<Page<P>>>
     where
         P: for<'de> Deserialize<'de>,
     {
@@ -224,10 +422,10 @@ impl NotionClient {
         }
 
         let mut cursor = None;
-        let mut output = FuturesOrdered::new();
+        let mut output = Vec::new();
 
         loop {
-            let url = format!("https://api.notion.com/v1/databases/{}/query", id);
+            let url = format!("{}/databases/{}/query", self.api_url, id);
             let list = self
                 .send_request::<List<Page<P>>>(
                     &url,
@@ -239,23 +437,497 @@ impl NotionClient {
                 )
                 .await?;
 
-            let requests = list
-                .results
-                .into_iter()
-                .map(|page| async {
-                    let children = self.get_block_children(page.id).await?;
-
-                    Ok(page.replace_children(children))
-                })
-                .collect::<Vec<_>>();
-
-            output.extend(requests);
+            output.extend(list.results);
 
             if list.has_more {
                 cursor = list.next_cursor;
             } else {
-                return output.try_collect().await;
+                return Ok(output);
+            }
+        }
+    }
+
+    /// Get the pages of a database along with their content
+    pub async fn get_database_pages<P>(&self, id: &str) -> Result<Vec<Page<P>>>
+    where
+        P: for<'de> Deserialize<'de>,
+    {
+        self.query_database(id)
+            .await?
+            .into_iter()
+            .map(|page| async {
+                let children = self.get_block_children(page.id).await?;
+
+                Ok(page.replace_children(children))
+            })
+            .collect::<FuturesOrdered<_>>()
+            .try_collect()
+            .await
+    }
+}
+
+/// Get the id of the block whose children are the content of `block`, keeping track of the synced
+/// blocks in `synced_blocks` that its content is inside of
+///
+/// Copies of a synced block don't have their own content, instead it's the content of the original
+/// synced block. Those can be copies of a synced block that they are themselves inside of, so this
+/// errors out instead of fetching the same blocks forever
+fn content_source(block: &Block, synced_blocks: &mut Vec<NotionId>) -> Result<NotionId> {
+    match &block.ty {
+        BlockType::SyncedBlock {
+            synced_from: Some(SyncedFrom { block_id }),
+            ..
+        } => {
+            if synced_blocks.contains(block_id) {
+                bail!(
+                    "Synced block {} is a copy of {} which it's inside of",
+                    block.id,
+                    block_id
+                );
             }
+            synced_blocks.push(*block_id);
+
+            Ok(*block_id)
+        }
+        BlockType::SyncedBlock {
+            synced_from: None, ..
+        } => {
+            synced_blocks.push(block.id);
+
+            Ok(block.id)
+        }
+        _ => Ok(block.id),
+    }
+}
+
+/// Whether an error is Notion refusing access to something, such as a database that isn't shared
+/// with the integration
+fn is_inaccessible(error: &anyhow::Error) -> bool {
+    matches!(
+        error.downcast_ref::<Error>(),
+        Some(Error {
+            code: ErrorCode::ObjectNotFound
+                | ErrorCode::RestrictedResource
+                | ErrorCode::ValidationError,
+            ..
+        })
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{content_source, NotionClient};
+    use crate::response::{Ancestor, Block, BlockType, SyncedFrom};
+    use reqwest::Client;
+    use serde_json::{json, Value};
+    use std::collections::HashMap;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    const PAGE: &str = "46f8638c25a84ccd9d926e42bdb5535e";
+    const ORIGINAL: &str = "f1e2d3c4b5a649788695a4b3c2d1e0f9";
+    const OTHER_ORIGINAL: &str = "2b3c4d5e6f7a48b99c0d1e2f3a4b5c6d";
+    const COPY: &str = "0a1b2c3d4e5f4a6b8c7d9e0f1a2b3c4d";
+
+    /// A client for a local server that responds to requests with the JSON in `responses` for their
+    /// paths, and with the error Notion responds with for objects that aren't shared with the
+    /// integration to requests for any other path
+    async fn local_client(responses: HashMap<String, Value>) -> NotionClient {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 4096];
+                let read = stream.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..read]);
+                let path = request
+                    .split(' ')
+                    .nth(1)
+                    .and_then(|target| target.split('?').next())
+                    .unwrap_or_default();
+
+                let (status, body) = match responses.get(path) {
+                    Some(body) => ("200 OK", body.clone()),
+                    None => (
+                        "404 Not Found",
+                        json!({
+                            "object": "error",
+                            "status": 404,
+                            "code": "object_not_found",
+                            "message": format!("Could not find {}", path),
+                        }),
+                    ),
+                };
+                let body = body.to_string();
+                let response = format!(
+                    "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        NotionClient {
+            svc: NotionClient::make_service(Client::new()),
+            auth_token: "secret_token".to_string(),
+            api_url: format!("http://{}", address),
+        }
+    }
+
+    fn page_json(id: &str, title: &str, parent: Value) -> Value {
+        json!({
+            "object": "page",
+            "id": id,
+            "created_time": "2022-12-10T15:12:00.000Z",
+            "last_edited_time": "2022-12-10T15:12:00.000Z",
+            "archived": false,
+            "icon": null,
+            "cover": null,
+            "properties": {
+                "title": {
+                    "id": "title",
+                    "type": "title",
+                    "title": [{
+                        "type": "text",
+                        "text": { "content": title, "link": null },
+                        "annotations": {
+                            "bold": false,
+                            "italic": false,
+                            "strikethrough": false,
+                            "underline": false,
+                            "code": false,
+                            "color": "default"
+                        },
+                        "plain_text": title,
+                        "href": null
+                    }]
+                }
+            },
+            "parent": parent,
+            "url": format!("https://www.notion.so/{}", id),
+        })
+    }
+
+    fn synced_block(id: &str, synced_from: Option<&str>) -> Block {
+        Block {
+            object: "block".to_string(),
+            id: id.parse().unwrap(),
+            created_time: "2022-12-10T15:12:00.000Z".to_string(),
+            last_edited_time: "2022-12-10T15:12:00.000Z".to_string(),
+            has_children: true,
+            archived: false,
+            ty: BlockType::SyncedBlock {
+                synced_from: synced_from.map(|block_id| SyncedFrom {
+                    block_id: block_id.parse().unwrap(),
+                }),
+                children: vec![],
+            },
         }
     }
+
+    fn synced_block_json(id: &str, synced_from: Option<&str>) -> Value {
+        json!({
+            "object": "block",
+            "id": id,
+            "created_time": "2022-12-10T15:12:00.000Z",
+            "last_edited_time": "2022-12-10T15:12:00.000Z",
+            "has_children": true,
+            "archived": false,
+            "type": "synced_block",
+            "synced_block": {
+                "synced_from": synced_from.map(|block_id| json!({
+                    "type": "block_id",
+                    "block_id": block_id,
+                })),
+            },
+        })
+    }
+
+    fn children_json(children: Vec<Value>) -> Value {
+        json!({
+            "object": "list",
+            "results": children,
+            "next_cursor": null,
+            "has_more": false,
+        })
+    }
+
+    #[test]
+    fn content_of_synced_blocks() {
+        let mut synced_blocks = Vec::new();
+        assert_eq!(
+            content_source(&synced_block(ORIGINAL, None), &mut synced_blocks).unwrap(),
+            ORIGINAL.parse().unwrap()
+        );
+        assert_eq!(synced_blocks, vec![ORIGINAL.parse().unwrap()]);
+
+        let mut synced_blocks = Vec::new();
+        assert_eq!(
+            content_source(&synced_block(COPY, Some(ORIGINAL)), &mut synced_blocks).unwrap(),
+            ORIGINAL.parse().unwrap()
+        );
+        assert_eq!(synced_blocks, vec![ORIGINAL.parse().unwrap()]);
+
+        let mut synced_blocks = vec![ORIGINAL.parse().unwrap()];
+        assert_eq!(
+            content_source(&synced_block(COPY, Some(ORIGINAL)), &mut synced_blocks)
+                .unwrap_err()
+                .to_string(),
+            format!("Synced block {COPY} is a copy of {ORIGINAL} which it's inside of")
+        );
+    }
+
+    #[tokio::test]
+    async fn copies_of_synced_blocks() {
+        // The same synced block can be copied many times next to each other
+        let client = local_client(HashMap::from([
+            (
+                format!("/blocks/{PAGE}/children"),
+                children_json(vec![
+                    synced_block_json(ORIGINAL, None),
+                    synced_block_json(COPY, Some(ORIGINAL)),
+                ]),
+            ),
+            (
+                format!("/blocks/{ORIGINAL}/children"),
+                children_json(vec![synced_block_json(OTHER_ORIGINAL, None)]),
+            ),
+            (
+                format!("/blocks/{OTHER_ORIGINAL}/children"),
+                children_json(vec![]),
+            ),
+        ]))
+        .await;
+
+        let blocks = client
+            .get_block_children(PAGE.parse().unwrap())
+            .await
+            .unwrap();
+        let other_original = synced_block(OTHER_ORIGINAL, None);
+        assert_eq!(
+            blocks
+                .iter()
+                .map(|block| block.get_children().unwrap())
+                .collect::<Vec<_>>(),
+            vec![[other_original.clone()], [other_original]]
+        );
+    }
+
+    #[tokio::test]
+    async fn self_referencing_synced_block() {
+        let client = local_client(HashMap::from([
+            (
+                format!("/blocks/{PAGE}/children"),
+                children_json(vec![synced_block_json(ORIGINAL, None)]),
+            ),
+            (
+                format!("/blocks/{ORIGINAL}/children"),
+                children_json(vec![synced_block_json(COPY, Some(ORIGINAL))]),
+            ),
+        ]))
+        .await;
+
+        assert_eq!(
+            client
+                .get_block_children(PAGE.parse().unwrap())
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!("Synced block {COPY} is a copy of {ORIGINAL} which it's inside of")
+        );
+        assert!(client
+            .get_block_children(ORIGINAL.parse().unwrap())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn synced_blocks_cycle() {
+        let client = local_client(HashMap::from([
+            (
+                format!("/blocks/{PAGE}/children"),
+                children_json(vec![synced_block_json(ORIGINAL, None)]),
+            ),
+            (
+                format!("/blocks/{ORIGINAL}/children"),
+                children_json(vec![synced_block_json(COPY, Some(OTHER_ORIGINAL))]),
+            ),
+            (
+                format!("/blocks/{OTHER_ORIGINAL}/children"),
+                children_json(vec![synced_block_json(COPY, Some(ORIGINAL))]),
+            ),
+        ]))
+        .await;
+
+        assert_eq!(
+            client
+                .get_block_children(PAGE.parse().unwrap())
+                .await
+                .unwrap_err()
+                .to_string(),
+            format!("Synced block {COPY} is a copy of {ORIGINAL} which it's inside of")
+        );
+        assert!(client
+            .get_block_children(ORIGINAL.parse().unwrap())
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn ancestors_up_to_an_inaccessible_one() {
+        const COLUMN: &str = "3c4d5e6f7a8b49c0b1d2e3f4a5b6c7d8";
+        const PARENT: &str = "7e8f9a0b1c2d43e4f5a6b7c8d9e0f1a2";
+        const DATABASE: &str = "5a6b7c8d9e0f41a2b3c4d5e6f7a8b9c0";
+
+        let client = local_client(HashMap::from([
+            (
+                format!("/pages/{PAGE}"),
+                page_json(
+                    PAGE,
+                    "Page",
+                    json!({ "type": "block_id", "block_id": COLUMN }),
+                ),
+            ),
+            (
+                format!("/blocks/{COLUMN}"),
+                json!({
+                    "object": "block",
+                    "id": COLUMN,
+                    "parent": { "type": "page_id", "page_id": PARENT },
+                }),
+            ),
+            (
+                format!("/pages/{PARENT}"),
+                page_json(
+                    PARENT,
+                    "Parent",
+                    json!({ "type": "database_id", "database_id": DATABASE }),
+                ),
+            ),
+        ]))
+        .await;
+
+        assert_eq!(
+            client.get_ancestors(PAGE.parse().unwrap()).await.unwrap(),
+            vec![
+                Ancestor {
+                    id: PARENT.parse().unwrap(),
+                    title: "Parent".to_string(),
+                },
+                Ancestor {
+                    id: PAGE.parse().unwrap(),
+                    title: "Page".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn breadcrumbs_inside_of_blocks() {
+        const TOGGLE: &str = "3c4d5e6f7a8b49c0b1d2e3f4a5b6c7d8";
+        const BREADCRUMB: &str = "7e8f9a0b1c2d43e4f5a6b7c8d9e0f1a2";
+
+        let client = local_client(HashMap::from([
+            (
+                format!("/blocks/{TOGGLE}/children"),
+                json!({
+                    "object": "list",
+                    "results": [{
+                        "object": "block",
+                        "id": BREADCRUMB,
+                        "created_time": "2022-12-10T15:12:00.000Z",
+                        "last_edited_time": "2022-12-10T15:12:00.000Z",
+                        "has_children": false,
+                        "archived": false,
+                        "type": "breadcrumb",
+                        "breadcrumb": {},
+                    }],
+                    "next_cursor": null,
+                    "has_more": false,
+                }),
+            ),
+            (
+                format!("/blocks/{BREADCRUMB}"),
+                json!({
+                    "object": "block",
+                    "id": BREADCRUMB,
+                    "parent": { "type": "block_id", "block_id": TOGGLE },
+                }),
+            ),
+            (
+                format!("/blocks/{TOGGLE}"),
+                json!({
+                    "object": "block",
+                    "id": TOGGLE,
+                    "parent": { "type": "page_id", "page_id": PAGE },
+                }),
+            ),
+            (
+                format!("/pages/{PAGE}"),
+                page_json(
+                    PAGE,
+                    "Page",
+                    json!({ "type": "workspace", "workspace": true }),
+                ),
+            ),
+        ]))
+        .await;
+
+        let blocks = client
+            .get_block_children(TOGGLE.parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            blocks.into_iter().map(|block| block.ty).collect::<Vec<_>>(),
+            vec![BlockType::Breadcrumb {
+                trail: vec![Ancestor {
+                    id: PAGE.parse().unwrap(),
+                    title: "Page".to_string(),
+                }],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn links_to_inaccessible_pages() {
+        const LINK: &str = "3c4d5e6f7a8b49c0b1d2e3f4a5b6c7d8";
+        const TARGET: &str = "7e8f9a0b1c2d43e4f5a6b7c8d9e0f1a2";
+
+        let client = local_client(HashMap::from([(
+            format!("/blocks/{PAGE}/children"),
+            json!({
+                "object": "list",
+                "results": [{
+                    "object": "block",
+                    "id": LINK,
+                    "created_time": "2022-12-10T15:12:00.000Z",
+                    "last_edited_time": "2022-12-10T15:12:00.000Z",
+                    "has_children": false,
+                    "archived": false,
+                    "type": "link_to_page",
+                    "link_to_page": { "type": "page_id", "page_id": TARGET },
+                }],
+                "next_cursor": null,
+                "has_more": false,
+            }),
+        )]))
+        .await;
+
+        let blocks = client
+            .get_block_children(PAGE.parse().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            blocks.into_iter().map(|block| block.ty).collect::<Vec<_>>(),
+            vec![BlockType::Unsupported {
+                ty: "link_to_page".to_string(),
+                raw: json!({ "type": "page_id", "page_id": TARGET }),
+            }]
+        );
+    }
 }
@@ -22,7 +22,7 @@ impl PartialEq for Downloadable {
 }
 impl PartialOrd for Downloadable {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.path.partial_cmp(&other.path)
+        Some(self.cmp(other))
     }
 }
 impl Ord for Downloadable {
@@ -58,6 +58,19 @@ impl Downloadable {
     pub fn src_path(&self) -> String {
         format!("/{}", self.path)
     }
+
+    /// Return the decoded name of the file from the last segment of its URL, if there's one
+    pub fn file_name(&self) -> Option<String> {
+        self.url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                percent_encoding::percent_decode_str(segment)
+                    .decode_utf8_lossy()
+                    .into_owned()
+            })
+    }
 }
 
 /// A list of things that needs downloading
@@ -120,6 +133,12 @@ impl Downloadables {
     }
 }
 
+impl Default for Downloadables {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{Downloadable, Downloadables, FILES_DIR};
@@ -178,9 +197,3 @@ mod tests {
         );
     }
 }
-
-impl Default for Downloadables {
-    fn default() -> Self {
-        Self::new()
-    }
-}
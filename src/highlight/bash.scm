@@ -0,0 +1,141 @@
+; Based on the Bash highlights from tree-sitter-bash with the captures mapped
+; onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-bash/blob/v0.20.5/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2017 Max Brunsfeld
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+[
+  (string)
+  (raw_string)
+  (ansi_c_string)
+  (heredoc_body)
+  (heredoc_start)
+  (heredoc_end)
+] @string
+
+[
+  (number)
+  (file_descriptor)
+] @constant.numeric
+
+; -------
+; Functions
+; -------
+
+(function_definition
+  name: (word) @function)
+(command_name) @function
+
+; Flags passed to commands, such as `-v` and `--verbose`
+((command
+  argument: (word) @constant)
+  (#match? @constant "^-"))
+
+; -------
+; Variables
+; -------
+
+(special_variable_name) @variable.builtin
+(variable_name) @variable
+
+; -------
+; Punctuation
+; -------
+
+(expansion
+  ["${" "}"] @punctuation.special)
+(command_substitution
+  ["$(" ")" "`"] @punctuation.special)
+(process_substitution
+  ["<(" ">(" ")"] @punctuation.special)
+
+[
+  ";"
+  ";;"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "(("
+  "))"
+  "["
+  "]"
+  "[["
+  "]]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+(test_operator) @operator
+
+[
+  "$"
+  "="
+  "=="
+  "!="
+  "=~"
+  "!"
+  "&"
+  "&&"
+  "|"
+  "|&"
+  "||"
+  ">"
+  ">>"
+  ">&"
+  "&>"
+  "&>>"
+  "<"
+  "<<"
+  "<<<"
+  "<<-"
+  "+="
+  "-="
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "case"
+  "declare"
+  "do"
+  "done"
+  "elif"
+  "else"
+  "esac"
+  "export"
+  "fi"
+  "for"
+  "function"
+  "if"
+  "in"
+  "local"
+  "readonly"
+  "select"
+  "then"
+  "typeset"
+  "unset"
+  "unsetenv"
+  "until"
+  "while"
+] @keyword
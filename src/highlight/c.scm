@@ -0,0 +1,203 @@
+; Based on the C highlights from tree-sitter-c with the captures mapped onto the
+; highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-c/blob/v0.20.8/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014 Max Brunsfeld
+;
+; These are also used for C++ after the C++ highlights, so they can only use
+; the nodes and tokens that are shared by both grammars
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+[
+  (string_literal)
+  (system_lib_string)
+  (char_literal)
+] @string
+(escape_sequence) @constant.character.escape
+
+(number_literal) @constant.numeric
+
+[
+  (true)
+  (false)
+  (null)
+] @constant.builtin
+
+; -------
+; Preprocessor
+; -------
+
+(preproc_function_def
+  name: (identifier) @function.macro)
+(preproc_def
+  name: (identifier) @constant)
+
+[
+  "#define"
+  "#elif"
+  "#else"
+  "#endif"
+  "#if"
+  "#ifdef"
+  "#ifndef"
+  "#include"
+  (preproc_directive)
+] @keyword
+
+; -------
+; Functions
+; -------
+
+(call_expression
+  function: (identifier) @function)
+(call_expression
+  function: (field_expression
+    field: (field_identifier) @function.method))
+(function_declarator
+  declarator: (identifier) @function)
+
+; -------
+; Types
+; -------
+
+; Captures the type in types like `unsigned int` without a highlight so that the
+; whole type is highlighted together
+(sized_type_specifier
+  type: (primitive_type) @none)
+[
+  (primitive_type)
+  (sized_type_specifier)
+] @type.builtin
+
+(type_identifier) @type
+
+; -------
+; Identifiers
+; -------
+
+((identifier) @constant
+  (#match? @constant "^[A-Z][A-Z\\d_]*$"))
+
+(statement_identifier) @label
+(field_identifier) @variable.other.member
+
+; -------
+; Punctuation
+; -------
+
+[
+  "."
+  ","
+  ":"
+  "::"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "[["
+  "]]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "--"
+  "-"
+  "-="
+  "->"
+  "="
+  "!"
+  "!="
+  "*"
+  "*="
+  "/"
+  "/="
+  "%"
+  "%="
+  "&"
+  "&&"
+  "&="
+  "^"
+  "^="
+  "|"
+  "|="
+  "||"
+  "~"
+  "+"
+  "++"
+  "+="
+  "<"
+  "<<"
+  "<<="
+  "<="
+  "=="
+  ">"
+  ">="
+  ">>"
+  ">>="
+  "?"
+  "..."
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "break"
+  "case"
+  "const"
+  "continue"
+  "default"
+  "defined"
+  "do"
+  "else"
+  "enum"
+  "extern"
+  "for"
+  "goto"
+  "if"
+  "inline"
+  "register"
+  "restrict"
+  "return"
+  "sizeof"
+  "static"
+  "struct"
+  "switch"
+  "typedef"
+  "union"
+  "volatile"
+  "while"
+  "_Atomic"
+  "_Noreturn"
+  "noreturn"
+  "thread_local"
+  "constexpr"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+(identifier) @variable
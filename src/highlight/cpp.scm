@@ -0,0 +1,111 @@
+; Based on the C++ highlights from tree-sitter-cpp with the captures mapped onto
+; the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-cpp/blob/v0.20.5/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014 Max Brunsfeld
+;
+; These only cover what C++ adds on top of C and are meant to come before the C
+; highlights so that they take precedence over them
+
+; -------
+; Literals
+; -------
+
+; Captures the parentheses around raw strings without a highlight so that they
+; are kept from being captured as punctuation in the C highlights
+(raw_string_literal
+  ["(" ")"] @none)
+(raw_string_literal) @string
+
+(this) @variable.builtin
+
+; -------
+; Functions
+; -------
+
+(call_expression
+  function: (qualified_identifier
+    name: (identifier) @function))
+(template_function
+  name: (identifier) @function)
+(template_method
+  name: (field_identifier) @function.method)
+(function_declarator
+  declarator: (qualified_identifier
+    name: (identifier) @function))
+(function_declarator
+  declarator: (field_identifier) @function.method)
+(function_declarator
+  declarator: (destructor_name
+    (identifier) @function.method))
+
+; -------
+; Types
+; -------
+
+(auto) @type.builtin
+(namespace_identifier) @namespace
+
+; -------
+; Punctuation
+; -------
+
+(template_parameter_list
+  ["<" ">"] @punctuation.bracket)
+(template_argument_list
+  ["<" ">"] @punctuation.bracket)
+
+; -------
+; Operators
+; -------
+
+[
+  "<=>"
+  "->*"
+  ".*"
+] @operator
+
+[
+  "and"
+  "not"
+  "or"
+] @keyword.operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "catch"
+  "class"
+  "co_await"
+  "co_return"
+  "co_yield"
+  "concept"
+  "consteval"
+  "constinit"
+  "decltype"
+  "delete"
+  "explicit"
+  "final"
+  "friend"
+  "mutable"
+  "namespace"
+  "new"
+  "noexcept"
+  "operator"
+  "override"
+  "private"
+  "protected"
+  "public"
+  "requires"
+  "static_assert"
+  "template"
+  "throw"
+  "try"
+  "typename"
+  "using"
+  "virtual"
+] @keyword
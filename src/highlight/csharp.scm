@@ -0,0 +1,316 @@
+; Based on the C# highlights from tree-sitter-c-sharp with the captures mapped
+; onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-c-sharp/blob/v0.20.0/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014-2020 Max Brunsfeld, Damien Guard and other contributors
+;
+; The types of C# are plain identifiers in its grammar, so instead of guessing
+; from their casing like the other highlights they are matched by where they
+; can appear
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+[
+  (string_literal)
+  (verbatim_string_literal)
+  (character_literal)
+  (interpolated_string_expression)
+] @string
+(escape_sequence) @constant.character.escape
+(interpolation
+  "{" @punctuation.special
+  "}" @punctuation.special)
+
+[
+  (integer_literal)
+  (real_literal)
+] @constant.numeric
+
+[
+  (boolean_literal)
+  (null_literal)
+] @constant.builtin
+
+[
+  (this_expression)
+  (base_expression)
+] @variable.builtin
+
+; -------
+; Attributes
+; -------
+
+; Like Rust attributes the whole attribute is captured together
+(attribute_list) @attribute
+
+; -------
+; Namespaces
+; -------
+
+; Captures the parts of qualified names without a highlight so that the whole
+; name is highlighted together
+(qualified_name
+  [
+    (identifier)
+    (qualified_name)
+    "."
+  ] @none)
+
+(using_directive
+  [
+    (identifier)
+    (qualified_name)
+  ] @namespace)
+(namespace_declaration
+  name: [
+    (identifier)
+    (qualified_name)
+  ] @namespace)
+(file_scoped_namespace_declaration
+  name: [
+    (identifier)
+    (qualified_name)
+  ] @namespace)
+
+; -------
+; Functions
+; -------
+
+(method_declaration
+  name: (identifier) @function.method)
+(local_function_statement
+  name: (identifier) @function)
+
+(invocation_expression
+  function: (member_access_expression
+    name: (identifier) @function.method))
+(invocation_expression
+  function: (member_access_expression
+    name: (generic_name
+      (identifier) @function.method)))
+(invocation_expression
+  function: (identifier) @function)
+(invocation_expression
+  function: (generic_name
+    (identifier) @function))
+
+(constructor_declaration
+  name: (identifier) @constructor)
+(destructor_declaration
+  name: (identifier) @constructor)
+
+; -------
+; Types
+; -------
+
+[
+  (predefined_type)
+  (void_keyword)
+] @type.builtin
+(implicit_type) @keyword
+
+(qualified_name) @type
+
+(class_declaration
+  name: (identifier) @type)
+(struct_declaration
+  name: (identifier) @type)
+(interface_declaration
+  name: (identifier) @type)
+(enum_declaration
+  name: (identifier) @type)
+(record_declaration
+  name: (identifier) @type)
+(record_struct_declaration
+  name: (identifier) @type)
+(type_parameter
+  name: (identifier) @type)
+
+(variable_declaration
+  type: (identifier) @type)
+(parameter
+  type: (identifier) @type)
+(property_declaration
+  type: (identifier) @type)
+(method_declaration
+  type: (identifier) @type)
+(local_function_statement
+  type: (identifier) @type)
+(object_creation_expression
+  type: (identifier) @type)
+(cast_expression
+  type: (identifier) @type)
+(catch_declaration
+  type: (identifier) @type)
+(array_type
+  type: (identifier) @type)
+(nullable_type
+  (identifier) @type)
+(type_argument_list
+  (identifier) @type)
+(type_of_expression
+  (identifier) @type)
+(base_list
+  (identifier) @type)
+(generic_name
+  (identifier) @type)
+
+; -------
+; Members
+; -------
+
+(enum_member_declaration
+  (identifier) @constant)
+(member_access_expression
+  name: (identifier) @variable.other.member)
+
+; -------
+; Punctuation
+; -------
+
+(type_argument_list
+  ["<" ">"] @punctuation.bracket)
+(type_parameter_list
+  ["<" ">"] @punctuation.bracket)
+
+[
+  "."
+  ","
+  ":"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "--"
+  "-"
+  "-="
+  "->"
+  "!"
+  "!="
+  "*"
+  "*="
+  "/"
+  "/="
+  "%"
+  "%="
+  "&"
+  "&&"
+  "&="
+  "^"
+  "^="
+  "+"
+  "++"
+  "+="
+  "<"
+  "<<"
+  "<<="
+  "<="
+  "="
+  "=="
+  "=>"
+  ">"
+  ">="
+  ">>"
+  ">>="
+  "|"
+  "|="
+  "||"
+  "~"
+  "?"
+  "??"
+  "??="
+  ".."
+] @operator
+
+; -------
+; Keywords
+; -------
+
+(modifier) @keyword
+
+[
+  "as"
+  "await"
+  "break"
+  "case"
+  "catch"
+  "checked"
+  "class"
+  "continue"
+  "default"
+  "delegate"
+  "do"
+  "else"
+  "enum"
+  "event"
+  "explicit"
+  "finally"
+  "fixed"
+  "for"
+  "foreach"
+  "from"
+  "get"
+  "goto"
+  "if"
+  "implicit"
+  "in"
+  "init"
+  "interface"
+  "is"
+  "let"
+  "lock"
+  "namespace"
+  "new"
+  "operator"
+  "out"
+  "params"
+  "record"
+  "ref"
+  "return"
+  "select"
+  "set"
+  "sizeof"
+  "stackalloc"
+  "struct"
+  "switch"
+  "throw"
+  "try"
+  "typeof"
+  "unchecked"
+  "using"
+  "when"
+  "where"
+  "while"
+  "with"
+  "yield"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+(identifier) @variable
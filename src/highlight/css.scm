@@ -0,0 +1,143 @@
+; Based on the CSS highlights from tree-sitter-css with the captures mapped onto
+; the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-css/blob/v0.20.0/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2018 Max Brunsfeld
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+[
+  (comment)
+  (js_comment)
+] @comment
+
+(string_value) @string
+(attribute_selector
+  (plain_value) @string)
+
+; Captures the `#` of colors without a highlight so that it's highlighted
+; together with the rest of the color
+(color_value
+  "#" @none)
+(color_value) @constant
+
+; Captures units without a highlight so that they are highlighted together with
+; their numbers
+(unit) @none
+[
+  (integer_value)
+  (float_value)
+] @constant.numeric
+
+; -------
+; Selectors
+; -------
+
+(pseudo_element_selector
+  (tag_name) @attribute)
+(pseudo_class_selector
+  (class_name) @attribute)
+
+[
+  (tag_name)
+  (nesting_selector)
+  (universal_selector)
+] @keyword.tag
+
+[
+  (class_name)
+  (id_name)
+  (namespace_name)
+] @type
+
+(attribute_name) @attribute
+
+; -------
+; Properties
+; -------
+
+((property_name) @variable
+  (#match? @variable "^--"))
+((plain_value) @variable
+  (#match? @variable "^--"))
+
+[
+  (property_name)
+  (feature_name)
+] @variable.other.member
+
+(function_name) @function
+
+; -------
+; Keywords
+; -------
+
+[
+  "@media"
+  "@import"
+  "@charset"
+  "@namespace"
+  "@supports"
+  "@keyframes"
+  (at_keyword)
+  (to)
+  (from)
+  (important)
+] @keyword
+
+[
+  "and"
+  "or"
+  "not"
+  "only"
+] @keyword.operator
+
+; -------
+; Operators
+; -------
+
+[
+  "~"
+  ">"
+  "+"
+  "-"
+  "*"
+  "/"
+  "="
+  "^="
+  "|="
+  "~="
+  "$="
+  "*="
+] @operator
+
+; -------
+; Punctuation
+; -------
+
+[
+  "#"
+  "."
+  ","
+  ":"
+  "::"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
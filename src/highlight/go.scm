@@ -0,0 +1,182 @@
+; Based on the Go highlights from tree-sitter-go with the captures mapped onto
+; the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-go/blob/v0.20.0/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014 Max Brunsfeld
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+[
+  (interpreted_string_literal)
+  (raw_string_literal)
+  (rune_literal)
+] @string
+(escape_sequence) @constant.character.escape
+
+[
+  (int_literal)
+  (float_literal)
+  (imaginary_literal)
+] @constant.numeric
+
+[
+  (true)
+  (false)
+  (nil)
+  (iota)
+] @constant.builtin
+
+; -------
+; Functions
+; -------
+
+((call_expression
+  function: (identifier) @function.builtin)
+  (#match?
+    @function.builtin
+    "^(append|cap|clear|close|complex|copy|delete|imag|len|make|max|min|new|panic|print|println|real|recover)$"))
+
+(call_expression
+  function: (identifier) @function)
+(call_expression
+  function: (selector_expression
+    field: (field_identifier) @function.method))
+
+(function_declaration
+  name: (identifier) @function)
+(method_declaration
+  name: (field_identifier) @function.method)
+(method_spec
+  name: (field_identifier) @function.method)
+
+; -------
+; Types
+; -------
+
+((type_identifier) @type.builtin
+  (#match?
+    @type.builtin
+    "^(any|bool|byte|comparable|complex64|complex128|error|float32|float64|int|int8|int16|int32|int64|rune|string|uint|uint8|uint16|uint32|uint64|uintptr)$"))
+(type_identifier) @type
+
+; -------
+; Identifiers
+; -------
+
+(package_identifier) @namespace
+(label_name) @label
+(field_identifier) @variable.other.member
+
+; -------
+; Punctuation
+; -------
+
+[
+  "."
+  ","
+  ":"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "--"
+  "-"
+  "-="
+  ":="
+  "!"
+  "!="
+  "..."
+  "*"
+  "*="
+  "/"
+  "/="
+  "&"
+  "&&"
+  "&="
+  "&^"
+  "&^="
+  "%"
+  "%="
+  "^"
+  "^="
+  "+"
+  "++"
+  "+="
+  "<-"
+  "<"
+  "<<"
+  "<<="
+  "<="
+  "="
+  "=="
+  ">"
+  ">="
+  ">>"
+  ">>="
+  "|"
+  "|="
+  "||"
+  "~"
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "break"
+  "case"
+  "chan"
+  "const"
+  "continue"
+  "default"
+  "defer"
+  "else"
+  "fallthrough"
+  "for"
+  "func"
+  "go"
+  "goto"
+  "if"
+  "import"
+  "interface"
+  "map"
+  "package"
+  "range"
+  "return"
+  "select"
+  "struct"
+  "switch"
+  "type"
+  "var"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+(identifier) @variable
@@ -0,0 +1,35 @@
+; Based on the HTML highlights from tree-sitter-html with the captures mapped
+; onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-html/blob/v0.20.0/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014 Max Brunsfeld
+;
+; The contents of `<script>` and `<style>` tags are highlighted as JavaScript
+; and CSS through the injections query of tree-sitter-html
+
+(comment) @comment
+(doctype) @constant
+
+[
+  (tag_name)
+  (erroneous_end_tag_name)
+] @keyword.tag
+
+(attribute_name) @attribute
+(quoted_attribute_value) @string
+(attribute
+  (attribute_value) @string)
+
+(entity) @constant.character.escape
+
+"=" @operator
+
+[
+  "<"
+  ">"
+  "</"
+  "/>"
+  "<!"
+] @punctuation.bracket
@@ -0,0 +1,242 @@
+; Based on the Java highlights from tree-sitter-java with the captures mapped
+; onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-java/blob/v0.20.2/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2017 Ayman Nadeem
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+[
+  (line_comment)
+  (block_comment)
+] @comment
+
+[
+  (character_literal)
+  (string_literal)
+] @string
+(escape_sequence) @constant.character.escape
+
+[
+  (hex_integer_literal)
+  (decimal_integer_literal)
+  (octal_integer_literal)
+  (binary_integer_literal)
+  (decimal_floating_point_literal)
+  (hex_floating_point_literal)
+] @constant.numeric
+
+[
+  (true)
+  (false)
+  (null_literal)
+] @constant.builtin
+
+[
+  (this)
+  (super)
+] @variable.builtin
+
+; -------
+; Annotations
+; -------
+
+(annotation
+  "@" @attribute
+  name: (identifier) @attribute)
+(marker_annotation
+  "@" @attribute
+  name: (identifier) @attribute)
+
+; -------
+; Functions
+; -------
+
+(method_declaration
+  name: (identifier) @function.method)
+(method_invocation
+  name: (identifier) @function.method)
+(constructor_declaration
+  name: (identifier) @constructor)
+
+; -------
+; Types
+; -------
+
+[
+  (boolean_type)
+  (integral_type)
+  (floating_point_type)
+  (void_type)
+] @type.builtin
+
+(type_identifier) @type
+
+; -------
+; Special Identifiers
+; -------
+
+((identifier) @constant
+  (#match? @constant "^_*[A-Z][A-Z\\d_]+$"))
+
+; Lowercase parts of qualified names, such as the ones in imports, are assumed
+; to be packages
+((scoped_identifier
+  (identifier) @namespace)
+  (#match? @namespace "^[a-z]"))
+((package_declaration
+  (identifier) @namespace)
+  (#match? @namespace "^[a-z]"))
+
+; PascalCase identifiers are assumed to be classes, such as the ones that have
+; their static members accessed or the ones that are imported
+((identifier) @type
+  (#match? @type "^[A-Z]"))
+
+(field_access
+  field: (identifier) @variable.other.member)
+
+; -------
+; Punctuation
+; -------
+
+(type_arguments
+  ["<" ">"] @punctuation.bracket)
+(type_parameters
+  ["<" ">"] @punctuation.bracket)
+
+[
+  "."
+  ","
+  ":"
+  "::"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "--"
+  "-"
+  "-="
+  "->"
+  "!"
+  "!="
+  "*"
+  "*="
+  "/"
+  "/="
+  "%"
+  "%="
+  "&"
+  "&&"
+  "&="
+  "^"
+  "^="
+  "+"
+  "++"
+  "+="
+  "<"
+  "<<"
+  "<<="
+  "<="
+  "="
+  "=="
+  ">"
+  ">="
+  ">>"
+  ">>="
+  ">>>"
+  ">>>="
+  "|"
+  "|="
+  "||"
+  "~"
+  "?"
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "abstract"
+  "assert"
+  "break"
+  "case"
+  "catch"
+  "class"
+  "continue"
+  "default"
+  "do"
+  "else"
+  "enum"
+  "exports"
+  "extends"
+  "final"
+  "finally"
+  "for"
+  "if"
+  "implements"
+  "import"
+  "instanceof"
+  "interface"
+  "module"
+  "native"
+  "new"
+  "non-sealed"
+  "open"
+  "opens"
+  "package"
+  "permits"
+  "private"
+  "protected"
+  "provides"
+  "public"
+  "record"
+  "requires"
+  "return"
+  "sealed"
+  "static"
+  "strictfp"
+  "switch"
+  "synchronized"
+  "throw"
+  "throws"
+  "to"
+  "transient"
+  "transitive"
+  "try"
+  "uses"
+  "volatile"
+  "when"
+  "while"
+  "with"
+  "yield"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+(identifier) @variable
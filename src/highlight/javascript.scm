@@ -0,0 +1,269 @@
+; Based on the JavaScript highlights from tree-sitter-javascript with the captures
+; mapped onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-javascript/blob/v0.20.4/queries/highlights.scm
+; https://github.com/tree-sitter/tree-sitter-javascript/blob/v0.20.4/queries/highlights-jsx.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2014 Max Brunsfeld
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; JSX
+; -------
+
+(jsx_opening_element
+  name: (identifier) @constructor
+  (#match? @constructor "^[A-Z]"))
+(jsx_closing_element
+  name: (identifier) @constructor
+  (#match? @constructor "^[A-Z]"))
+(jsx_self_closing_element
+  name: (identifier) @constructor
+  (#match? @constructor "^[A-Z]"))
+
+(jsx_opening_element
+  name: (identifier) @keyword.tag)
+(jsx_closing_element
+  name: (identifier) @keyword.tag)
+(jsx_self_closing_element
+  name: (identifier) @keyword.tag)
+
+(jsx_attribute
+  (property_identifier) @attribute)
+
+(jsx_opening_element
+  ["<" ">"] @punctuation.bracket)
+(jsx_closing_element
+  ["</" ">"] @punctuation.bracket)
+(jsx_self_closing_element
+  ["<" "/>"] @punctuation.bracket)
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+[
+  (string)
+  (template_string)
+  (regex)
+] @string
+; Captures the slashes around regexes without a highlight so that they are kept
+; from being captured as operators below
+(regex
+  "/" @none)
+
+(template_substitution
+  "${" @punctuation.special
+  "}" @punctuation.special)
+
+(number) @constant.numeric
+
+[
+  (true)
+  (false)
+  (null)
+  (undefined)
+] @constant.builtin
+
+[
+  (this)
+  (super)
+] @variable.builtin
+
+; -------
+; Functions
+; -------
+
+(function_declaration
+  name: (identifier) @function)
+(function_expression
+  name: (identifier) @function)
+(generator_function_declaration
+  name: (identifier) @function)
+(method_definition
+  name: (property_identifier) @function.method)
+
+(pair
+  key: (property_identifier) @function.method
+  value: [(function_expression) (arrow_function)])
+
+(assignment_expression
+  left: (member_expression
+    property: (property_identifier) @function.method)
+  right: [(function_expression) (arrow_function)])
+
+(variable_declarator
+  name: (identifier) @function
+  value: [(function_expression) (arrow_function)])
+
+(assignment_expression
+  left: (identifier) @function
+  right: [(function_expression) (arrow_function)])
+
+(new_expression
+  constructor: (identifier) @constructor)
+
+(call_expression
+  function: (identifier) @function)
+(call_expression
+  function: (member_expression
+    property: (property_identifier) @function.method))
+
+; -------
+; Special Identifiers
+; -------
+
+([
+  (identifier)
+  (shorthand_property_identifier)
+  (shorthand_property_identifier_pattern)
+] @constant
+  (#match? @constant "^[A-Z_][A-Z\\d_]+$"))
+
+((identifier) @type
+  (#match? @type "^[A-Z]"))
+
+((identifier) @variable.builtin
+  (#match? @variable.builtin "^(arguments|module|console|window|document|require)$"))
+
+(statement_identifier) @label
+
+; -------
+; Punctuation
+; -------
+
+[
+  ";"
+  (optional_chain)
+  "."
+  ","
+  ":"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "-"
+  "--"
+  "-="
+  "+"
+  "++"
+  "+="
+  "*"
+  "*="
+  "**"
+  "**="
+  "/"
+  "/="
+  "%"
+  "%="
+  "<"
+  "<="
+  "<<"
+  "<<="
+  "="
+  "=="
+  "==="
+  "!"
+  "!="
+  "!=="
+  "=>"
+  ">"
+  ">="
+  ">>"
+  ">>="
+  ">>>"
+  ">>>="
+  "~"
+  "^"
+  "&"
+  "|"
+  "^="
+  "&="
+  "|="
+  "&&"
+  "||"
+  "??"
+  "&&="
+  "||="
+  "??="
+  "?"
+  "..."
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "as"
+  "async"
+  "await"
+  "break"
+  "case"
+  "catch"
+  "class"
+  "const"
+  "continue"
+  "debugger"
+  "default"
+  "delete"
+  "do"
+  "else"
+  "export"
+  "extends"
+  "finally"
+  "for"
+  "from"
+  "function"
+  "get"
+  "if"
+  "import"
+  "in"
+  "instanceof"
+  "let"
+  "new"
+  "of"
+  "return"
+  "set"
+  "static"
+  "switch"
+  "target"
+  "throw"
+  "try"
+  "typeof"
+  "var"
+  "void"
+  "while"
+  "with"
+  "yield"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+[
+  (identifier)
+  (shorthand_property_identifier)
+  (shorthand_property_identifier_pattern)
+] @variable
+(property_identifier) @variable.other.member
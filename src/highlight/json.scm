@@ -0,0 +1,29 @@
+; Highlights for JSON mapped onto the highlights used by the Rust ones
+;
+; Like the Rust highlights, the more specific patterns come first and take
+; precedence over the general ones
+
+(pair
+  key: (string) @variable.other.member)
+
+(string) @string
+(escape_sequence) @constant.character.escape
+(number) @constant.numeric
+
+[
+  (true)
+  (false)
+  (null)
+] @constant.builtin
+
+[
+  ","
+  ":"
+] @punctuation.delimiter
+
+[
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
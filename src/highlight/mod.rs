@@ -1,11 +1,29 @@
 use crate::response::{Language, NotionId};
 use anyhow::{bail, Context, Result};
 use maud::{html, Markup, PreEscaped};
-use tree_sitter_highlight::{HighlightConfiguration, Highlighter, HtmlRenderer};
+use once_cell::sync::Lazy;
+use std::{collections::HashMap, ops::Range};
+use tree_sitter_highlight::{Highlight, HighlightConfiguration, HighlightEvent, Highlighter};
 
+const BASH_HIGHLIGHTS: &str = include_str!("./bash.scm");
+const C_HIGHLIGHTS: &str = include_str!("./c.scm");
+const CPP_HIGHLIGHTS: &str = include_str!("./cpp.scm");
+const CSHARP_HIGHLIGHTS: &str = include_str!("./csharp.scm");
+const CSS_HIGHLIGHTS: &str = include_str!("./css.scm");
+const GO_HIGHLIGHTS: &str = include_str!("./go.scm");
+const HTML_HIGHLIGHTS: &str = include_str!("./html.scm");
+const JAVA_HIGHLIGHTS: &str = include_str!("./java.scm");
+const JAVASCRIPT_HIGHLIGHTS: &str = include_str!("./javascript.scm");
+const JSON_HIGHLIGHTS: &str = include_str!("./json.scm");
+const PYTHON_HIGHLIGHTS: &str = include_str!("./python.scm");
 const RUST_HIGHLIGHTS: &str = include_str!("./rust.scm");
+const SQL_HIGHLIGHTS: &str = include_str!("./sql.scm");
+const TYPESCRIPT_HIGHLIGHTS: &str = include_str!("./typescript.scm");
 
-const HIGHLIGHTS: [&str; 18] = [
+/// The names of the highlights that are turned into classes, the dots in them are turned into
+/// spaces to get the classes such that `constant.numeric` gets the classes `constant numeric`, and
+/// `turbofish` also gets the class `punctuation`
+pub const HIGHLIGHTS: [&str; 18] = [
     "attribute",
     "comment",
     "constant",
@@ -26,72 +44,426 @@ const HIGHLIGHTS: [&str; 18] = [
     "variable",
 ];
 
-pub fn highlight(lang: &Language, code: &str, id: NotionId) -> Result<Markup> {
-    let (tree_sitter_lang, highlights, code, lang_name) = match (lang, code) {
-        (Language::PlainText, code) => {
-            return Ok(html! {
-                pre id=(id) class="plain_text" {
-                    code class="plain_text" {
-                        (code)
-                    }
-                }
-            });
+/// A range of the code that is wrapped in extra tags, such as the annotations and links of rich
+/// text
+pub struct Span {
+    pub range: Range<usize>,
+    pub opening: String,
+    pub closing: String,
+}
+
+/// The languages that code can be highlighted in, along with how to highlight each of them
+///
+/// The default registry contains the languages that are built into the crate, more languages can
+/// be registered on top of them with [LanguageRegistry::register]
+///
+/// The queries of the built-in languages are only compiled the first time code in them is
+/// highlighted, since compiling all of them takes a few seconds
+pub struct LanguageRegistry {
+    languages: HashMap<Language, RegisteredLanguage>,
+}
+
+struct RegisteredLanguage {
+    config: Lazy<HighlightConfiguration, Box<dyn FnOnce() -> HighlightConfiguration + Send>>,
+    class_name: String,
+}
+
+impl LanguageRegistry {
+    /// Create a registry without any languages, not even the built-in ones
+    pub fn new() -> Self {
+        LanguageRegistry {
+            languages: HashMap::new(),
         }
-        (Language::Rust, code) => (tree_sitter_rust::language(), RUST_HIGHLIGHTS, code, "rust"),
-        (Language::Toml, code) => (
-            tree_sitter_toml::language(),
+    }
+
+    /// Register a tree-sitter grammar and its queries to highlight code in a language with,
+    /// replacing the previous registration of the language if there's one
+    ///
+    /// The captures of the highlights query should use the names in [HIGHLIGHTS] for them to be
+    /// turned into classes, and the class name is added to the rendered `pre` and `code` tags
+    ///
+    /// Languages injected by the injections query are looked up by their class name, such that an
+    /// injection with `(#set! injection.language "css")` is highlighted by the language
+    /// registered with the class name `css`
+    ///
+    /// Errors if one of the queries isn't valid for the grammar
+    pub fn register(
+        &mut self,
+        lang: Language,
+        grammar: tree_sitter::Language,
+        highlights_query: &str,
+        injections_query: &str,
+        locals_query: &str,
+        class_name: impl Into<String>,
+    ) -> Result<()> {
+        let config = configuration(grammar, highlights_query, injections_query, locals_query)?;
+        self.languages.insert(
+            lang,
+            RegisteredLanguage {
+                config: Lazy::new(Box::new(move || config)),
+                class_name: class_name.into(),
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Register a built-in language whose queries are only compiled the first time it's used
+    fn register_built_in(
+        &mut self,
+        lang: Language,
+        grammar: fn() -> tree_sitter::Language,
+        highlights_query: impl Into<String>,
+        injections_query: &'static str,
+        class_name: &'static str,
+    ) {
+        let highlights_query = highlights_query.into();
+        self.languages.insert(
+            lang,
+            RegisteredLanguage {
+                config: Lazy::new(Box::new(move || {
+                    configuration(grammar(), &highlights_query, injections_query, "")
+                        .unwrap_or_else(|_| panic!("Built-in {class_name} queries should be valid"))
+                })),
+                class_name: class_name.to_string(),
+            },
+        );
+    }
+
+    /// Whether code in the language can be highlighted with [highlight]
+    pub fn is_supported(&self, lang: &Language) -> bool {
+        matches!(lang, Language::PlainText) || self.languages.contains_key(lang)
+    }
+
+    fn injected(&self, name: &str) -> Option<&HighlightConfiguration> {
+        self.languages
+            .values()
+            .find(|registered| registered.class_name == name)
+            .map(|registered| &*registered.config)
+    }
+}
+
+/// Compile the queries of a tree-sitter grammar into a configuration that highlights with the
+/// names in [HIGHLIGHTS]
+fn configuration(
+    grammar: tree_sitter::Language,
+    highlights_query: &str,
+    injections_query: &str,
+    locals_query: &str,
+) -> Result<HighlightConfiguration> {
+    let mut config =
+        HighlightConfiguration::new(grammar, highlights_query, injections_query, locals_query)
+            .context("Failed to parse tree_sitter config")?;
+    config.configure(&HIGHLIGHTS);
+
+    Ok(config)
+}
+
+impl Default for LanguageRegistry {
+    fn default() -> Self {
+        let mut registry = LanguageRegistry::new();
+
+        registry.register_built_in(
+            Language::Rust,
+            tree_sitter_rust::language,
+            RUST_HIGHLIGHTS,
+            "",
+            "rust",
+        );
+        registry.register_built_in(
+            Language::Toml,
+            tree_sitter_toml::language,
             tree_sitter_toml::HIGHLIGHT_QUERY,
-            code,
+            "",
             "toml",
-        ),
-        _ => bail!(
+        );
+        // Notion has no separate labels for JSX and TSX so the JavaScript grammar, which parses
+        // JSX, and the TSX grammar are used for all JavaScript and TypeScript code respectively
+        registry.register_built_in(
+            Language::JavaScript,
+            tree_sitter_javascript::language,
+            JAVASCRIPT_HIGHLIGHTS,
+            "",
+            "javascript",
+        );
+        registry.register_built_in(
+            Language::TypeScript,
+            tree_sitter_typescript::language_tsx,
+            [TYPESCRIPT_HIGHLIGHTS, JAVASCRIPT_HIGHLIGHTS].concat(),
+            "",
+            "typescript",
+        );
+        registry.register_built_in(
+            Language::Json,
+            tree_sitter_json::language,
+            JSON_HIGHLIGHTS,
+            "",
+            "json",
+        );
+        registry.register_built_in(
+            Language::Python,
+            tree_sitter_python::language,
+            PYTHON_HIGHLIGHTS,
+            "",
+            "python",
+        );
+        // Shell scripts in the wild are mostly written for Bash or are compatible with it
+        for (lang, class_name) in [(Language::Bash, "bash"), (Language::Shell, "shell")] {
+            registry.register_built_in(
+                lang,
+                tree_sitter_bash::language,
+                BASH_HIGHLIGHTS,
+                "",
+                class_name,
+            );
+        }
+        // Notion has a single label for every dialect of SQL, BigQuery's grammar is used for all of
+        // them since its standard SQL dialect covers the statements that are shared by most
+        // databases, while syntax that's specific to other databases is left unhighlighted
+        registry.register_built_in(
+            Language::Sql,
+            tree_sitter_sql_bigquery::language,
+            SQL_HIGHLIGHTS,
+            "",
+            "sql",
+        );
+        // Notion's markup label is mostly used for HTML
+        for (lang, class_name) in [(Language::Html, "html"), (Language::Markup, "markup")] {
+            registry.register_built_in(
+                lang,
+                tree_sitter_html::language,
+                HTML_HIGHLIGHTS,
+                tree_sitter_html::INJECTIONS_QUERY,
+                class_name,
+            );
+        }
+        // There's no SCSS grammar for this version of tree-sitter, but SCSS is a superset of CSS
+        // and the CSS grammar also parses nested rules and `//` comments
+        for (lang, class_name) in [(Language::Css, "css"), (Language::Scss, "scss")] {
+            registry.register_built_in(
+                lang,
+                tree_sitter_css::language,
+                CSS_HIGHLIGHTS,
+                "",
+                class_name,
+            );
+        }
+        registry.register_built_in(Language::C, tree_sitter_c::language, C_HIGHLIGHTS, "", "c");
+        // Notion's combined Java/C/C++/C# label is highlighted as C++ since its grammar also parses C,
+        // and the classes and generics of Java and C# are close enough to its classes and templates
+        for (lang, class_name) in [
+            (Language::CPlusPlus, "c_plus_plus"),
+            (Language::CLike, "c_like"),
+        ] {
+            registry.register_built_in(
+                lang,
+                tree_sitter_cpp::language,
+                [CPP_HIGHLIGHTS, C_HIGHLIGHTS].concat(),
+                "",
+                class_name,
+            );
+        }
+        registry.register_built_in(
+            Language::CSharp,
+            tree_sitter_c_sharp::language,
+            CSHARP_HIGHLIGHTS,
+            "",
+            "c_sharp",
+        );
+        registry.register_built_in(
+            Language::Java,
+            tree_sitter_java::language,
+            JAVA_HIGHLIGHTS,
+            "",
+            "java",
+        );
+        registry.register_built_in(
+            Language::Go,
+            tree_sitter_go::language,
+            GO_HIGHLIGHTS,
+            "",
+            "go",
+        );
+
+        registry
+    }
+}
+
+/// The name of the language as it's used in class names
+pub fn language_name(lang: &Language) -> Option<String> {
+    match serde_json::to_value(lang).ok()? {
+        serde_json::Value::String(lang_name) => Some(lang_name),
+        _ => None,
+    }
+}
+
+/// Render code without any highlighting, only wrapping the parts of it in spans in their tags
+pub fn plain_text(lang_name: &str, code: &str, spans: &[Span], id: Option<NotionId>) -> Markup {
+    let mut renderer = CodeRenderer::new(code, spans, &[]);
+    renderer.add_text(0, code.len());
+
+    html! {
+        pre id=[id] class=(lang_name) {
+            code class=(lang_name) {
+                (PreEscaped(renderer.html))
+            }
+        }
+    }
+}
+
+pub fn highlight(
+    languages: &LanguageRegistry,
+    lang: &Language,
+    code: &str,
+    spans: &[Span],
+    id: Option<NotionId>,
+) -> Result<Markup> {
+    if let Language::PlainText = lang {
+        return Ok(plain_text("plain_text", code, spans, id));
+    }
+
+    let RegisteredLanguage { config, class_name } = match languages.languages.get(lang) {
+        Some(registered) => registered,
+        None => bail!(
             "Unsupported language {}",
-            serde_json::to_value(lang)
-                .ok()
-                .and_then(
-                    |value| if let serde_json::Value::String(lang_name) = value {
-                        Some(lang_name)
-                    } else {
-                        None
-                    }
-                )
-                .context("Unsupported language with unserializable name")?
+            language_name(lang).context("Unsupported language with unserializable name")?
         ),
     };
 
-    let mut config = HighlightConfiguration::new(tree_sitter_lang, highlights, "", "")
-        .context("Failed to parse tree_sitter config")?;
-    config.configure(&HIGHLIGHTS);
     let mut highlighter = Highlighter::new();
-    let mut renderer = HtmlRenderer::new();
-
     let events = highlighter
-        .highlight(&config, code.as_bytes(), None, |_| None)
-        .unwrap();
+        .highlight(config, code.as_bytes(), None, |name| {
+            languages.injected(name)
+        })
+        .context("Failed to highlight code")?;
 
     let classes = HIGHLIGHTS.map(|highlight| {
-        format!(
-            r#"class="{}""#,
-            highlight
-                .replace('.', " ")
-                .replace("turbofish", "punctuation turbofish")
-        )
-        .into_bytes()
+        highlight
+            .replace('.', " ")
+            .replace("turbofish", "punctuation turbofish")
     });
 
-    renderer
-        .render(events, code.as_bytes(), &|highlight| &classes[highlight.0])
-        .context("Failed to render code")?;
+    let mut renderer = CodeRenderer::new(code, spans, &classes);
+    for event in events {
+        match event.context("Failed to render code")? {
+            HighlightEvent::HighlightStart(highlight) => renderer.start_highlight(highlight),
+            HighlightEvent::HighlightEnd => renderer.end_highlight(),
+            HighlightEvent::Source { start, end } => renderer.add_text(start, end),
+        }
+    }
+    if !renderer.html.ends_with('\n') {
+        renderer.html.push('\n');
+    }
 
     Ok(html! {
-        pre id=(id) class=(lang_name) {
-            code class=(lang_name) {
-                @for line in renderer.lines() {
-                    // TreeSitter HtmlRenderer already handles escaping
-                    (PreEscaped(line))
+        pre id=[id] class=(class_name) {
+            code class=(class_name) {
+                // CodeRenderer already handles escaping
+                (PreEscaped(renderer.html))
+            }
+        }
+    })
+}
+
+/// Renders tree-sitter's highlights as HTML much like tree-sitter's own `HtmlRenderer`, while
+/// also wrapping parts of the code in the tags of their spans
+///
+/// The tags of spans are always the innermost ones and are closed and reopened around highlights
+/// and line breaks to keep the HTML well nested
+struct CodeRenderer<'a> {
+    html: String,
+    code: &'a str,
+    spans: &'a [Span],
+    classes: &'a [String],
+    highlights: Vec<Highlight>,
+}
+
+impl<'a> CodeRenderer<'a> {
+    fn new(code: &'a str, spans: &'a [Span], classes: &'a [String]) -> Self {
+        CodeRenderer {
+            html: String::with_capacity(code.len()),
+            code,
+            spans,
+            classes,
+            highlights: Vec::new(),
+        }
+    }
+
+    fn start_highlight(&mut self, highlight: Highlight) {
+        self.highlights.push(highlight);
+        self.open_highlight(highlight);
+    }
+
+    fn end_highlight(&mut self) {
+        self.highlights.pop();
+        self.html.push_str("</span>");
+    }
+
+    fn open_highlight(&mut self, highlight: Highlight) {
+        self.html.push_str(r#"<span class=""#);
+        self.html.push_str(&self.classes[highlight.0]);
+        self.html.push_str(r#"">"#);
+    }
+
+    fn add_text(&mut self, start: usize, end: usize) {
+        let mut position = start;
+
+        while position < end {
+            let span = self
+                .spans
+                .iter()
+                .find(|span| span.range.contains(&position));
+            let piece_end = match span {
+                Some(span) => span.range.end,
+                None => self
+                    .spans
+                    .iter()
+                    .map(|span| span.range.start)
+                    .filter(|start| *start > position)
+                    .min()
+                    .unwrap_or(end),
+            }
+            .min(end);
+
+            let mut lines = self.code[position..piece_end].split('\n').peekable();
+            while let Some(line) = lines.next() {
+                if !line.is_empty() {
+                    if let Some(span) = span {
+                        self.html.push_str(&span.opening);
+                    }
+                    escape(&mut self.html, line);
+                    if let Some(span) = span {
+                        self.html.push_str(&span.closing);
+                    }
+                }
+
+                // At line boundaries, close and re-open all of the open highlights
+                if lines.peek().is_some() {
+                    self.highlights
+                        .iter()
+                        .for_each(|_| self.html.push_str("</span>"));
+                    self.html.push('\n');
+                    for highlight in self.highlights.clone() {
+                        self.open_highlight(highlight);
+                    }
                 }
             }
+
+            position = piece_end;
         }
+    }
+}
+
+/// Escape text the same way tree-sitter's `HtmlRenderer` does, dropping carriage returns
+fn escape(buffer: &mut String, text: &str) {
+    text.chars().for_each(|character| match character {
+        '>' => buffer.push_str("&gt;"),
+        '<' => buffer.push_str("&lt;"),
+        '&' => buffer.push_str("&amp;"),
+        '\'' => buffer.push_str("&#39;"),
+        '"' => buffer.push_str("&quot;"),
+        '\r' => {}
+        character => buffer.push(character),
     })
 }
 
@@ -99,9 +471,10 @@ pub fn highlight(lang: &Language, code: &str, id: NotionId) -> Result<Markup> {
 mod tests {
     use std::{fs, path::Path};
 
-    use super::highlight;
+    use super::{highlight, LanguageRegistry};
     use crate::response::Language;
     use insta::Settings;
+    use once_cell::sync::Lazy;
 
     #[test]
     fn highlighting_tests() {
@@ -109,6 +482,7 @@ mod tests {
         settings.set_prepend_module_to_snapshot(false);
         settings.set_snapshot_path("tests");
         settings.set_omit_expression(true);
+        let languages = LanguageRegistry::default();
 
         let tests_dir = Path::new(file!())
             .parent()
@@ -150,10 +524,12 @@ mod tests {
                     insta::assert_snapshot!(
                         snap_name,
                         highlight(
+                            &languages,
                             &serde_json::from_str::<Language>(&format!("\"{lang}\""))
-                                .expect(&format!("unexpected language {lang}")),
+                                .unwrap_or_else(|_| panic!("unexpected language {lang}")),
                             &code,
-                            "5e845049255f423296fd6f20449be0bc".parse().unwrap()
+                            &[],
+                            "5e845049255f423296fd6f20449be0bc".parse().ok()
                         )
                         .unwrap()
                         .into_string()
@@ -161,4 +537,72 @@ mod tests {
                 })
             });
     }
+
+    #[test]
+    fn built_in_queries_are_valid() {
+        let languages = LanguageRegistry::default();
+        for registered in languages.languages.values() {
+            Lazy::force(&registered.config);
+        }
+    }
+
+    #[test]
+    fn register_language() {
+        let mut languages = LanguageRegistry::new();
+        assert!(!languages.is_supported(&Language::Ebnf));
+        assert!(languages.is_supported(&Language::PlainText));
+
+        languages
+            .register(
+                Language::Ebnf,
+                tree_sitter_toml::language(),
+                "(bare_key) @variable",
+                "",
+                "",
+                "in_house_dsl",
+            )
+            .unwrap();
+        assert!(languages.is_supported(&Language::Ebnf));
+        assert_eq!(
+            highlight(
+                &languages,
+                &Language::Ebnf,
+                "dsl = 1",
+                &[],
+                "5e845049255f423296fd6f20449be0bc".parse().ok()
+            )
+            .unwrap()
+            .into_string(),
+            r#"<pre id="5e845049255f423296fd6f20449be0bc" class="in_house_dsl"><code class="in_house_dsl"><span class="variable">dsl</span> = 1"#
+                .to_string()
+                + "\n"
+                + "</code></pre>"
+        );
+
+        assert!(languages
+            .register(
+                Language::Ebnf,
+                tree_sitter_toml::language(),
+                "(not_a_node) @variable",
+                "",
+                "",
+                "in_house_dsl",
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn highlight_c_like() {
+        let languages = LanguageRegistry::default();
+        let code = "int main() { return 0; }";
+
+        let c_like = highlight(&languages, &Language::CLike, code, &[], None)
+            .unwrap()
+            .into_string();
+        let c_plus_plus = highlight(&languages, &Language::CPlusPlus, code, &[], None)
+            .unwrap()
+            .into_string();
+        assert_eq!(c_like, c_plus_plus.replace("c_plus_plus", "c_like"));
+        assert!(c_like.starts_with(r#"<pre class="c_like"><code class="c_like">"#));
+    }
 }
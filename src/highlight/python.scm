@@ -0,0 +1,234 @@
+; Based on the Python highlights from tree-sitter-python with the captures
+; mapped onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-python/blob/v0.20.4/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2016 Max Brunsfeld
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+
+(string) @string
+(escape_sequence) @constant.character.escape
+(interpolation
+  "{" @punctuation.special
+  "}" @punctuation.special)
+
+[
+  (integer)
+  (float)
+] @constant.numeric
+
+[
+  (none)
+  (true)
+  (false)
+] @constant.builtin
+
+; -------
+; Decorators
+; -------
+
+; Like Rust attributes the whole decorator is captured together
+(decorator) @attribute
+
+; -------
+; Special Identifiers
+; -------
+
+((identifier) @constant
+  (#match? @constant "^[A-Z][A-Z\\d_]+$"))
+
+; PascalCase identifiers are assumed to be classes, including when they are
+; called to create instances of them
+((identifier) @type
+  (#match? @type "^[A-Z]"))
+
+((identifier) @variable.builtin
+  (#match? @variable.builtin "^(self|cls)$"))
+
+; -------
+; Functions
+; -------
+
+(function_definition
+  name: (identifier) @function)
+
+((call
+  function: (identifier) @function.builtin)
+  (#match?
+    @function.builtin
+    "^(abs|all|any|ascii|bin|bool|breakpoint|bytearray|bytes|callable|chr|classmethod|compile|complex|delattr|dict|dir|divmod|enumerate|eval|exec|filter|float|format|frozenset|getattr|globals|hasattr|hash|help|hex|id|input|int|isinstance|issubclass|iter|len|list|locals|map|max|memoryview|min|next|object|oct|open|ord|pow|print|property|range|repr|reversed|round|set|setattr|slice|sorted|staticmethod|str|sum|super|tuple|type|vars|zip|__import__)$"))
+
+(call
+  function: (attribute
+    attribute: (identifier) @function.method))
+(call
+  function: (identifier) @function)
+
+; -------
+; Types
+; -------
+
+(class_definition
+  name: (identifier) @type)
+(type
+  (identifier) @type)
+
+; -------
+; Modules
+; -------
+
+(import_statement
+  name: (dotted_name
+    (identifier) @namespace))
+(import_statement
+  name: (aliased_import
+    name: (dotted_name
+      (identifier) @namespace)))
+(import_from_statement
+  module_name: (dotted_name
+    (identifier) @namespace))
+
+; -------
+; Parameters
+; -------
+
+(parameters
+  (identifier) @variable.parameter)
+(default_parameter
+  name: (identifier) @variable.parameter)
+(typed_parameter
+  (identifier) @variable.parameter)
+(typed_default_parameter
+  name: (identifier) @variable.parameter)
+(keyword_argument
+  name: (identifier) @variable.parameter)
+
+; -------
+; Punctuation
+; -------
+
+[
+  "."
+  ","
+  ":"
+  ";"
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+  "{"
+  "}"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "-"
+  "-="
+  "!="
+  "*"
+  "**"
+  "**="
+  "*="
+  "/"
+  "//"
+  "//="
+  "/="
+  "&"
+  "&="
+  "%"
+  "%="
+  "^"
+  "^="
+  "+"
+  "->"
+  "+="
+  "<"
+  "<<"
+  "<<="
+  "<="
+  "<>"
+  "="
+  ":="
+  "=="
+  ">"
+  ">="
+  ">>"
+  ">>="
+  "|"
+  "|="
+  "~"
+  "@="
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "and"
+  "in"
+  "is"
+  "is not"
+  "not"
+  "not in"
+  "or"
+] @keyword.operator
+
+[
+  "as"
+  "assert"
+  "async"
+  "await"
+  "break"
+  "class"
+  "continue"
+  "def"
+  "del"
+  "elif"
+  "else"
+  "except"
+  "exec"
+  "finally"
+  "for"
+  "from"
+  "global"
+  "if"
+  "import"
+  "lambda"
+  "nonlocal"
+  "pass"
+  "print"
+  "raise"
+  "return"
+  "try"
+  "while"
+  "with"
+  "yield"
+  "match"
+  "case"
+] @keyword
+
+; -------
+; Remaining Identifiers
+; -------
+
+(attribute
+  attribute: (identifier) @variable.other.member)
+(identifier) @variable
@@ -0,0 +1,212 @@
+; Based on the SQL highlights from tree-sitter-sql-bigquery with the captures
+; mapped onto the highlights used by the Rust ones
+; https://github.com/takegue/tree-sitter-sql-bigquery/blob/v0.8.0/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2021 takegue
+;
+; The grammar is for BigQuery's dialect of SQL but it parses the statements
+; shared by most dialects as well
+
+; -------
+; Like the Rust highlights, the order is flipped from the original so that the
+; more specific patterns come first and take precedence over the general ones
+; -------
+
+; -------
+; Literals
+; -------
+
+(comment) @comment
+(string) @string
+(number) @constant.numeric
+; The numbers of LIMIT and OFFSET are hidden inside of the clause by the grammar
+; so there's no node to capture them with and they are left unhighlighted
+
+[
+  "NULL"
+  "TRUE"
+  "FALSE"
+] @constant.builtin
+
+; -------
+; Types
+; -------
+
+[
+  (struct)
+  (array)
+  (interval)
+  (type_identifier)
+  (type)
+  (column_type)
+] @type.builtin
+
+[
+  "DATE"
+  "TIME"
+  "DATETIME"
+  "TIMESTAMP"
+  "NUMERIC"
+  "BIGNUMERIC"
+  "DECIMAL"
+  "BIGDECIMAL"
+] @type.builtin
+
+; -------
+; Functions
+; -------
+
+(function_call
+  function: (identifier) @function)
+(call_statement
+  routine_name: (identifier) @function)
+
+; -------
+; Variables
+; -------
+
+[
+  "_PARTITIONDATE"
+  "_PARTITIONTIME"
+  "_TABLE_SUFFIX"
+] @variable.builtin
+(system_variable) @variable.builtin
+(query_parameter) @variable.parameter
+
+; Captures the dots in qualified names without a highlight so that the whole name
+; is highlighted as a single identifier
+(identifier
+  "." @none)
+(identifier) @variable
+
+; -------
+; Punctuation
+; -------
+
+[
+  ";"
+  "."
+  ","
+] @punctuation.delimiter
+
+[
+  "("
+  ")"
+  "["
+  "]"
+] @punctuation.bracket
+
+; -------
+; Operators
+; -------
+
+[
+  "-"
+  "*"
+  "/"
+  "^"
+  "+"
+  "<"
+  "<="
+  "<>"
+  "="
+  "!="
+  ">"
+  ">="
+  ">>"
+  "<<"
+  "||"
+  "~"
+] @operator
+
+; -------
+; Keywords
+; -------
+
+[
+  "ADD"
+  "ALL"
+  "ALTER"
+  "AND"
+  "AS"
+  "ASC"
+  "BEGIN"
+  "BETWEEN"
+  "CASE"
+  "CAST"
+  "COLUMN"
+  "CREATE"
+  "CROSS_JOIN"
+  "DECLARE"
+  "DEFAULT"
+  "DELETE"
+  "DESC"
+  "DISTINCT"
+  "DROP"
+  "ELSE"
+  "END"
+  "EXCEPT"
+  "EXISTS"
+  "FOLLOWING"
+  "FOR"
+  "FROM"
+  "FULL"
+  "FUNCTION"
+  "GROUP_BY"
+  "HAVING"
+  "IF"
+  "IF_EXISTS"
+  "IF_NOT_EXISTS"
+  "IN"
+  "INNER"
+  "INSERT"
+  "INTERVAL"
+  "INTO"
+  "IS"
+  "JOIN"
+  "LEFT"
+  "LIKE"
+  "LIMIT"
+  "MERGE"
+  "NOT"
+  "NOT_NULL"
+  "OFFSET"
+  "ON"
+  "OPTIONS"
+  "OR"
+  "OR_REPLACE"
+  "ORDER_BY"
+  "OUTER"
+  "OVER"
+  "PARTITION_BY"
+  "PRECEDING"
+  "PRIMARY_KEY"
+  "QUALIFY"
+  "RANGE"
+  "REFERENCES"
+  "RETURN"
+  "RETURNS"
+  "RIGHT"
+  "ROLLUP"
+  "ROWS"
+  "SCHEMA"
+  "SELECT"
+  "SET"
+  "TABLE"
+  "THEN"
+  "TO"
+  "UNION_ALL"
+  "UNION_DISTINCT"
+  "UNNEST"
+  "UPDATE"
+  "UPDATE_SET"
+  "USING"
+  "VALUES"
+  "VIEW"
+  "WHEN"
+  "WHERE"
+  "WINDOW"
+  "WITH"
+] @keyword
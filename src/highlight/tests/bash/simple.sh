@@ -0,0 +1,15 @@
+#!/usr/bin/env bash
+set -euo pipefail
+
+# Build every crate in the workspace
+function build() {
+  local target="${1:-debug}"
+  for crate in crates/*; do
+    if [[ -d "$crate" ]]; then
+      cargo build --manifest-path "$crate/Cargo.toml" 2>&1 | tee -a build.log
+    fi
+  done
+  echo "Built $(ls crates | wc -l) crates for $target" > /dev/null
+}
+
+build "$@"
@@ -0,0 +1,37 @@
+using System;
+using System.Collections.Generic;
+
+namespace Example.Shapes
+{
+    [Serializable]
+    public class Circle : IShape
+    {
+        private const double Pi = 3.14159;
+
+        public double Radius { get; set; }
+
+        public Circle(double radius)
+        {
+            this.Radius = radius;
+        }
+
+        // Computes the area of the circle
+        public double Area() => Pi * Radius * Radius;
+
+        public static List<Circle> Parse(string[] lines)
+        {
+            var circles = new List<Circle>();
+            foreach (var line in lines)
+            {
+                if (double.TryParse(line, out var radius) && radius > 0)
+                {
+                    circles.Add(new Circle(radius));
+                }
+            }
+            Console.WriteLine($"Parsed {circles.Count} circles\n");
+            return circles ?? null;
+        }
+    }
+
+    public enum Color { Red, Green }
+}
@@ -0,0 +1,35 @@
+#include <iostream>
+#include <vector>
+
+namespace geometry {
+
+template <typename T>
+class Point {
+public:
+    Point(T x, T y) : x_(x), y_(y) {}
+    virtual ~Point() = default;
+
+    T length() const noexcept;
+
+private:
+    T x_, y_;
+};
+
+template <typename T>
+T Point<T>::length() const noexcept {
+    return std::sqrt(x_ * x_ + y_ * y_);
+}
+
+} // namespace geometry
+
+int main() {
+    std::vector<geometry::Point<double>> points{{3.0, 4.0}};
+    auto first = points.front();
+    for (const auto& point : points) {
+        std::cout << point.length() << std::endl;
+    }
+    auto* ptr = new int(42);
+    delete ptr;
+    const char* raw = R"(a "raw" string)";
+    return this == nullptr ? 1 : 0;
+}
@@ -0,0 +1,35 @@
+#include <stdio.h>
+#include "list.h"
+
+#define MAX_ITEMS 16
+#define SQUARE(x) ((x) * (x))
+
+typedef struct Node {
+    int value;
+    struct Node *next;
+} Node;
+
+static unsigned long count = 0;
+
+/* Sums the values of a linked list */
+int sum(const Node *node) {
+    int total = 0;
+    while (node != NULL) {
+        total += node->value;
+        node = node->next;
+    }
+    return total;
+}
+
+int main(void) {
+    char greeting[] = "Hello\n";
+    printf("%s %d\n", greeting, SQUARE(MAX_ITEMS));
+    for (int i = 0; i < MAX_ITEMS; i++) {
+        if (i % 2 == 0 && i > 4) {
+            goto done;
+        }
+        count++;
+    }
+done:
+    return 0;
+}
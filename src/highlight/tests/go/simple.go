@@ -0,0 +1,36 @@
+package main
+
+import (
+	"fmt"
+	"strings"
+)
+
+// Shape is anything with an area
+type Shape interface {
+	Area() float64
+}
+
+type Circle struct {
+	Radius float64
+}
+
+func (c Circle) Area() float64 {
+	return 3.14159 * c.Radius * c.Radius
+}
+
+func parse(lines []string) ([]Circle, error) {
+	circles := make([]Circle, 0, len(lines))
+	for _, line := range lines {
+		if strings.TrimSpace(line) == "" {
+			continue
+		}
+		circles = append(circles, Circle{Radius: 1.5})
+	}
+	return circles, nil
+}
+
+func main() {
+	ch := make(chan int)
+	go func() { ch <- 42 }()
+	defer fmt.Println(`raw string`, 'x', <-ch, true)
+}
@@ -0,0 +1,37 @@
+package com.example.shapes;
+
+import java.util.ArrayList;
+import java.util.List;
+
+/**
+ * A circle with a radius
+ */
+public class Circle implements Shape {
+    private static final double PI = 3.14159;
+    private final double radius;
+
+    public Circle(double radius) {
+        this.radius = radius;
+    }
+
+    @Override
+    public double area() {
+        return PI * radius * radius;
+    }
+
+    public static List<Circle> parse(String[] lines) {
+        List<Circle> circles = new ArrayList<>();
+        for (String line : lines) {
+            // Skip blank lines
+            if (line.isBlank()) {
+                continue;
+            }
+            circles.add(new Circle(Double.parseDouble(line)));
+        }
+        System.out.println("Parsed " + circles.size() + " circles\n");
+        return circles.isEmpty() ? null : circles;
+    }
+
+    @SuppressWarnings("unchecked")
+    enum Color { RED, GREEN }
+}
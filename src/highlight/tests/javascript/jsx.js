@@ -0,0 +1,12 @@
+import { useState } from "react";
+
+function Counter({ start }) {
+  const [count, setCount] = useState(start);
+
+  return (
+    <div className="counter">
+      <Button onClick={() => setCount(count + 1)}>Clicked {count} times</Button>
+      <br />
+    </div>
+  );
+}
@@ -0,0 +1,16 @@
+// Count the words in a sentence
+const MAX_WORDS = 100;
+
+export async function countWords(sentence) {
+  const words = sentence.split(/\s+/);
+  if (words.length > MAX_WORDS) {
+    throw new Error(`Too many words: ${words.length}`);
+  }
+
+  return words.reduce((counts, word) => {
+    counts[word] = (counts[word] ?? 0) + 1;
+    return counts;
+  }, {});
+}
+
+console.log(this, null, undefined, true);
@@ -0,0 +1,20 @@
+import math
+from dataclasses import dataclass
+
+MAX_RADIUS = 10
+
+
+@dataclass
+class Circle:
+    """A circle in the plane"""
+
+    radius: float = 1.0
+
+    def area(self, scale: int = 2) -> float:
+        if self.radius > MAX_RADIUS and not scale:
+            raise ValueError(f"Radius {self.radius!r} is too big\n")
+        return math.pi * self.radius ** 2 * scale
+
+
+circles = [Circle(radius=r) for r in range(3) if r is not None]
+print(len(circles), True, None)  # Prints 3
@@ -0,0 +1,2 @@
+export NOTION_TOKEN='secret'
+notion-generator 46ce88507ab748c78f92024dc1190ca7 --output ./public && echo done
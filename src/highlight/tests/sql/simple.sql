@@ -0,0 +1,8 @@
+-- Most active users in the last week
+SELECT users.name, COUNT(*) AS posts
+FROM users
+INNER JOIN posts ON posts.author_id = users.id
+WHERE posts.created_at > '2022-01-01' AND users.deleted IS NULL
+GROUP BY users.name
+ORDER BY posts DESC
+LIMIT 10;
@@ -0,0 +1,21 @@
+interface Point {
+  x: number;
+  y?: number;
+}
+
+type Shape = "circle" | "square";
+
+export class Canvas<T extends Point> {
+  private readonly points: T[] = [];
+
+  constructor(public name: string) {}
+
+  add(point: T, label?: string): void {
+    this.points.push(point);
+  }
+}
+
+enum Direction {
+  Up = 1,
+  Down,
+}
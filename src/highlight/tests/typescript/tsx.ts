@@ -0,0 +1,3 @@
+type Props = { title: string };
+
+export const Title = ({ title }: Props): JSX.Element => <h1 id="title">{title}</h1>;
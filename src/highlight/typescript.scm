@@ -0,0 +1,61 @@
+; Based on the TypeScript highlights from tree-sitter-typescript with the captures
+; mapped onto the highlights used by the Rust ones
+; https://github.com/tree-sitter/tree-sitter-typescript/blob/v0.20.5/queries/highlights.scm
+;
+; Reused under the terms and conditions of MIT license
+;
+; Copyright (c) 2017 GitHub
+;
+; These only cover what TypeScript adds on top of JavaScript and are meant to
+; come before the JavaScript highlights so that they take precedence over them
+
+; -------
+; Types
+; -------
+
+; Keeps `void` from being captured as a keyword inside of the type
+(predefined_type
+  "void" @type.builtin)
+((predefined_type) @type.builtin
+  (#not-eq? @type.builtin "void"))
+(type_identifier) @type
+
+(type_arguments
+  ["<" ">"] @punctuation.bracket)
+(type_parameters
+  ["<" ">"] @punctuation.bracket)
+
+(optional_parameter
+  "?" @punctuation.delimiter)
+(property_signature
+  "?" @punctuation.delimiter)
+
+; -------
+; Variables
+; -------
+
+(required_parameter
+  (identifier) @variable.parameter)
+(optional_parameter
+  (identifier) @variable.parameter)
+
+; -------
+; Keywords
+; -------
+
+[
+  "abstract"
+  "declare"
+  "enum"
+  "implements"
+  "interface"
+  "keyof"
+  "namespace"
+  "private"
+  "protected"
+  "public"
+  "type"
+  "readonly"
+  "override"
+  "satisfies"
+] @keyword
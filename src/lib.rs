@@ -1,8 +1,10 @@
 pub mod client;
 pub mod download;
-mod highlight;
+pub mod highlight;
 pub mod options;
 pub mod render;
 pub mod response;
+pub mod unfurl;
+pub mod warnings;
 
 pub use render::HtmlRenderer;
@@ -1,6 +1,72 @@
+use time::OffsetDateTime;
+
 #[derive(Clone, Copy)]
 pub enum HeadingAnchors<'a> {
     None,
     Before(&'a str),
     After(&'a str),
 }
+
+/// How to render the entries of a database that's inside of a page
+#[derive(Clone, Copy)]
+pub enum DatabaseDisplay {
+    /// A list of links to each of the database's pages
+    List,
+    /// A table of the properties of each of the database's pages, where the title links to the page
+    Table,
+}
+
+/// What to do with blocks that can't be rendered, such as blocks of types that aren't supported yet
+#[derive(Clone, Copy)]
+pub enum UnsupportedBlocks {
+    /// Leave them out of the page
+    Skip,
+    /// Render a placeholder mentioning the type of the block in its place
+    Placeholder,
+    /// Fail rendering the page
+    Fail,
+}
+
+/// What to do with code blocks in languages that can't be highlighted
+#[derive(Clone, Copy)]
+pub enum UnsupportedLanguages {
+    /// Render them as plain text that still carries the class of their language, emitting a
+    /// warning
+    PlainText,
+    /// Fail rendering the page
+    Fail,
+}
+
+/// How to render users that are mentioned in text
+#[derive(Clone, Copy)]
+pub struct UserMentions {
+    /// Render the avatar of the user before their name, if they have one
+    pub avatars: bool,
+    /// Link the name of the user to their email, if it's known
+    pub email_links: bool,
+}
+
+/// What to render mentions that are left over from templates as, Notion usually replaces these
+/// with the date or the user when a page is created from a template
+pub struct TemplateMentions {
+    /// The time to render `@Now` and `@Today` mentions as, defaults to the time of rendering
+    pub now: OffsetDateTime,
+    /// The user to render `@Me` mentions as, if there's none they're rendered as plain text
+    pub me: Option<TemplateUser>,
+}
+
+impl Default for TemplateMentions {
+    fn default() -> Self {
+        TemplateMentions {
+            now: OffsetDateTime::now_utc(),
+            me: None,
+        }
+    }
+}
+
+/// A user that `@Me` template mentions are rendered as
+pub struct TemplateUser {
+    pub name: String,
+    pub avatar_url: Option<String>,
+    pub email: Option<String>,
+}
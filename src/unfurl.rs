@@ -0,0 +1,672 @@
+use crate::download::{Downloadable, FILES_DIR};
+use crate::response::{Block, BlockType, RichTextMentionType, RichTextType};
+use anyhow::{bail, Context, Result};
+use futures_util::stream::{self, StreamExt};
+use reqwest::{header::CONTENT_TYPE, Client, Url};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashSet},
+    future::Future,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    pin::Pin,
+    time::Duration,
+};
+
+/// How long fetching a page can take before it's given up on, so that a single slow host can't
+/// hold up the generation of the whole page
+const FETCH_TIMEOUT: Duration = Duration::from_secs(10);
+/// How many pages are fetched at the same time
+const CONCURRENT_FETCHES: usize = 8;
+
+/// Fetches the HTML of the pages that link previews point to
+///
+/// This is implemented for [reqwest::Client] but can be implemented by anything else that can
+/// fetch pages
+pub trait Fetcher {
+    fn fetch<'a>(&'a self, url: &'a str) -> Pin<Box<dyn Future<Output = Result<String>> + 'a>>;
+}
+
+impl Fetcher for Client {
+    fn fetch<'a>(&'a self, url: &'a str) -> Pin<Box<dyn Future<Output = Result<String>> + 'a>> {
+        Box::pin(async move {
+            let response = self
+                .get(url)
+                .timeout(FETCH_TIMEOUT)
+                .send()
+                .await?
+                .error_for_status()?;
+
+            let is_html = response
+                .headers()
+                .get(CONTENT_TYPE)
+                .and_then(|content_type| content_type.to_str().ok())
+                .map(|content_type| content_type.contains("html"))
+                .unwrap_or(false);
+            if !is_html {
+                bail!("{} is not an HTML page", url);
+            }
+
+            Ok(response.text().await?)
+        })
+    }
+}
+
+/// The title and icon of a page that a link preview points to
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UnfurledLink {
+    /// The page's `og:title`, falling back to its `<title>`
+    pub title: Option<String>,
+    /// The URL of the page's favicon, if it declares one
+    pub icon: Option<String>,
+}
+
+impl UnfurledLink {
+    /// Extract the title and icon from the HTML of the page at `url`
+    pub fn from_html(html: &str, url: &Url) -> Self {
+        let mut og_title = None;
+        let mut title = None;
+        let mut icon = None;
+
+        // Lowercasing ASCII keeps the byte offsets the same, so we can search in the lowercased
+        // HTML and slice the original one
+        let lowercased = html.to_ascii_lowercase();
+        let mut offset = 0;
+        while let Some(start) = lowercased[offset..].find('<') {
+            offset += start + 1;
+
+            if lowercased[offset..].starts_with("!--") {
+                match lowercased[offset..].find("-->") {
+                    Some(end) => offset += end + 3,
+                    None => break,
+                }
+                continue;
+            }
+
+            let (tag, attributes, end) = parse_tag(&html[offset..]);
+            offset += end;
+
+            match tag.as_str() {
+                "meta" if og_title.is_none() => {
+                    let is_og_title = attributes
+                        .iter()
+                        .any(|(name, value)| name == "property" && value == "og:title");
+                    if is_og_title {
+                        og_title = find_attribute(&attributes, "content").and_then(collapse);
+                    }
+                }
+                "title" if title.is_none() => {
+                    let end = lowercased[offset..]
+                        .find("</title")
+                        .unwrap_or(html.len() - offset);
+                    title = collapse(&decode_entities(&html[offset..offset + end]));
+                    offset += end;
+                }
+                "link" if icon.is_none() => {
+                    let is_icon = find_attribute(&attributes, "rel")
+                        .map(|rel| rel.split_whitespace().any(|rel| rel == "icon"))
+                        .unwrap_or(false);
+                    if is_icon {
+                        icon = find_attribute(&attributes, "href")
+                            .and_then(|href| url.join(href).ok())
+                            .map(String::from);
+                    }
+                }
+                // Everything we're interested in is in the head
+                "body" | "/head" => break,
+                _ => {}
+            }
+        }
+
+        UnfurledLink {
+            title: og_title.or(title),
+            icon,
+        }
+    }
+
+    /// The favicon of the page as a downloadable, if it has a valid one
+    ///
+    /// Icons are named after their host, a hash of their URL and their file name since many pages
+    /// share the same icon, while different icons of the same host often share the same file name
+    /// i.e media/github.githubassets.com-9f920c9038773e27-favicon.svg
+    pub fn icon_as_downloadable(&self) -> Option<Downloadable> {
+        let url = Url::parse(self.icon.as_deref()?).ok()?;
+        let file_name = url
+            .path_segments()
+            .and_then(|mut segments| segments.next_back())
+            .filter(|segment| !segment.is_empty())
+            .unwrap_or("favicon");
+        let name = format!(
+            "{}-{:016x}-{}",
+            url.host_str()?,
+            stable_hash(url.as_str()),
+            file_name
+        )
+        .chars()
+        .map(|character| match character {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '-' | '_' => character,
+            _ => '_',
+        })
+        .collect::<String>();
+
+        let mut path = PathBuf::from(FILES_DIR);
+        path.push(name);
+
+        Downloadable::new(url, path).ok()
+    }
+}
+
+/// Hash text with FNV-1a, which unlike the hashers of the standard library is guaranteed to give
+/// the same hash between runs, so that downloaded icons keep the same name
+fn stable_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Parse the tag that starts right after a `<`, returning its lowercased name, its attributes
+/// and the offset right after its closing `>`
+fn parse_tag(input: &str) -> (String, Vec<(String, String)>, usize) {
+    let is_name =
+        |character: char| character.is_ascii_alphanumeric() || character == '-' || character == '/';
+    let name_end = input
+        .find(|character| !is_name(character))
+        .unwrap_or(input.len());
+    let name = input[..name_end].to_ascii_lowercase();
+
+    let mut attributes = Vec::new();
+    let mut rest = &input[name_end..];
+    loop {
+        rest = rest
+            .trim_start_matches(|character: char| character.is_whitespace() || character == '/');
+
+        if rest.is_empty() {
+            break;
+        }
+        if let Some(after) = rest.strip_prefix('>') {
+            rest = after;
+            break;
+        }
+
+        let attribute_end = rest
+            .find(|character: char| {
+                character.is_whitespace() || matches!(character, '=' | '>' | '/')
+            })
+            .unwrap_or(rest.len())
+            .max(1);
+        let attribute = rest[..attribute_end].to_ascii_lowercase();
+        rest = rest[attribute_end..].trim_start();
+
+        let value = match rest.strip_prefix('=') {
+            Some(after) => {
+                let after = after.trim_start();
+                match after.chars().next() {
+                    Some(quote @ ('"' | '\'')) => {
+                        let value_end = after[1..].find(quote).map(|end| end + 1);
+                        let value_end = value_end.unwrap_or(after.len());
+                        rest = after.get(value_end + 1..).unwrap_or("");
+                        &after[1..value_end]
+                    }
+                    _ => {
+                        let value_end = after
+                            .find(|character: char| character.is_whitespace() || character == '>')
+                            .unwrap_or(after.len());
+                        rest = &after[value_end..];
+                        &after[..value_end]
+                    }
+                }
+            }
+            None => "",
+        };
+
+        attributes.push((attribute, decode_entities(value)));
+    }
+
+    (name, attributes, input.len() - rest.len())
+}
+
+fn find_attribute<'a>(attributes: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|(attribute, _)| attribute == name)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Collapse runs of whitespace into single spaces, returning nothing if there's only whitespace
+fn collapse(text: &str) -> Option<String> {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if collapsed.is_empty() {
+        None
+    } else {
+        Some(collapsed)
+    }
+}
+
+/// Decode the character references that are likely to show up in titles
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let reference = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 8)
+            .map(|end| &rest[1..end + 1]);
+        let character = reference.and_then(|reference| match reference {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some('\u{a0}'),
+            _ => reference
+                .strip_prefix("#x")
+                .or_else(|| reference.strip_prefix("#X"))
+                .map(|hex| u32::from_str_radix(hex, 16))
+                .or_else(|| reference.strip_prefix('#').map(str::parse))
+                .and_then(Result::ok)
+                .and_then(char::from_u32),
+        });
+
+        match (reference, character) {
+            (Some(reference), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[reference.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+
+    decoded
+}
+
+/// Link previews that were unfurled ahead of rendering, by their URL
+///
+/// These can be saved to and loaded from a cache on disk to avoid fetching the same pages every
+/// time a page is generated
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct UnfurledLinks {
+    links: BTreeMap<String, UnfurledLink>,
+}
+
+impl UnfurledLinks {
+    pub fn new() -> Self {
+        UnfurledLinks {
+            links: BTreeMap::new(),
+        }
+    }
+
+    /// Load previously unfurled links from the cache at `path`, if there's one
+    pub async fn load(path: &Path) -> Result<Self> {
+        match tokio::fs::read(path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .with_context(|| format!("Failed to parse unfurl cache {}", path.display())),
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::new()),
+            Err(error) => Err(error)
+                .with_context(|| format!("Failed to read unfurl cache {}", path.display())),
+        }
+    }
+
+    /// Save the unfurled links to the cache at `path`
+    pub async fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_vec_pretty(self).context("Failed to serialize unfurl cache")?;
+
+        tokio::fs::write(path, json)
+            .await
+            .with_context(|| format!("Failed to write unfurl cache {}", path.display()))
+    }
+
+    pub fn get(&self, url: &str) -> Option<&UnfurledLink> {
+        self.links.get(url)
+    }
+
+    pub fn insert(&mut self, url: String, link: UnfurledLink) -> Option<UnfurledLink> {
+        self.links.insert(url, link)
+    }
+
+    /// Fetch and unfurl all the link previews and bookmarks inside of `blocks` that weren't
+    /// unfurled already
+    ///
+    /// Pages that fail to be fetched are skipped with a warning, and their link previews and
+    /// bookmarks are rendered without titles and icons
+    pub async fn unfurl_blocks<F>(&mut self, fetcher: &F, blocks: &[Block])
+    where
+        F: Fetcher,
+    {
+        let mut urls = HashSet::new();
+        collect_link_previews(blocks, &mut urls);
+
+        let urls = urls
+            .into_iter()
+            .filter(|url| !self.links.contains_key(*url))
+            .collect::<Vec<_>>();
+        let mut fetches = stream::iter(urls)
+            .map(|url| async move {
+                let unfurled = async {
+                    let parsed_url = Url::parse(url)?;
+                    let html = fetcher.fetch(url).await?;
+
+                    Ok::<_, anyhow::Error>(UnfurledLink::from_html(&html, &parsed_url))
+                };
+
+                (url, unfurled.await)
+            })
+            .buffer_unordered(CONCURRENT_FETCHES);
+
+        while let Some((url, unfurled)) = fetches.next().await {
+            match unfurled {
+                Ok(unfurled) => {
+                    self.links.insert(url.to_string(), unfurled);
+                }
+                Err(error) => eprintln!("WARNING: Failed to unfurl link {}: {:#}", url, error),
+            }
+        }
+    }
+}
+
+fn collect_link_previews<'a>(blocks: &'a [Block], urls: &mut HashSet<&'a str>) {
+    blocks.iter().for_each(|block| {
+        if let BlockType::Bookmark { url, .. } = &block.ty {
+            urls.insert(url);
+        }
+
+        let cells = match &block.ty {
+            BlockType::TableRow { cells } => cells.as_slice(),
+            _ => &[],
+        };
+
+        block
+            .get_text()
+            .into_iter()
+            .chain(block.get_caption())
+            .chain(cells.iter().map(Vec::as_slice))
+            .flatten()
+            .for_each(|rich_text| {
+                if let RichTextType::Mention {
+                    mention: RichTextMentionType::LinkPreview { url },
+                } = &rich_text.ty
+                {
+                    urls.insert(url);
+                }
+            });
+
+        if let Some(children) = block.get_children() {
+            collect_link_previews(children, urls);
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Fetcher, UnfurledLink, UnfurledLinks};
+    use crate::download::Downloadable;
+    use crate::response::{Block, BlockType, Color, RichText, RichTextMentionType, RichTextType};
+    use anyhow::{bail, Result};
+    use reqwest::{Client, Url};
+    use std::{collections::HashSet, future::Future, pin::Pin};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    #[test]
+    fn extract_title_and_icon() {
+        let url = Url::parse("https://gamediary.dev/posts/flocking").unwrap();
+
+        assert_eq!(
+            UnfurledLink::from_html(
+                r#"<!DOCTYPE html>
+                <html>
+                  <head>
+                    <!-- <title>Commented out</title> -->
+                    <title>
+                      Flocking &amp; boids
+                    </title>
+                    <meta property="og:title" content="Flocking with Bevy &#x2014; Game Diary" />
+                    <link rel="stylesheet" href="/style.css">
+                    <link rel="shortcut icon" href=/favicon.png>
+                  </head>
+                  <body>
+                    <link rel="icon" href="/not-the-favicon.png">
+                  </body>
+                </html>"#,
+                &url
+            ),
+            UnfurledLink {
+                title: Some("Flocking with Bevy \u{2014} Game Diary".to_string()),
+                icon: Some("https://gamediary.dev/favicon.png".to_string()),
+            }
+        );
+
+        assert_eq!(
+            UnfurledLink::from_html(
+                "<HTML><HEAD><TITLE>Flocking &amp; boids</TITLE></HEAD></HTML>",
+                &url
+            ),
+            UnfurledLink {
+                title: Some("Flocking & boids".to_string()),
+                icon: None,
+            }
+        );
+
+        assert_eq!(
+            UnfurledLink::from_html("not even HTML", &url),
+            UnfurledLink::default()
+        );
+    }
+
+    #[test]
+    fn icon_as_downloadable() {
+        let link = UnfurledLink {
+            title: None,
+            icon: Some("https://github.githubassets.com/favicons/favicon%20dark.svg".to_string()),
+        };
+
+        assert_eq!(
+            link.icon_as_downloadable(),
+            Some(
+                Downloadable::new(
+                    Url::parse("https://github.githubassets.com/favicons/favicon%20dark.svg")
+                        .unwrap(),
+                    "media/github.githubassets.com-1c170ab486b08ea8-favicon_20dark.svg".into()
+                )
+                .unwrap()
+            )
+        );
+        assert_eq!(UnfurledLink::default().icon_as_downloadable(), None);
+    }
+
+    #[test]
+    fn icons_with_the_same_file_name_on_the_same_host() {
+        let icon = |url: &str| {
+            UnfurledLink {
+                title: None,
+                icon: Some(url.to_string()),
+            }
+            .icon_as_downloadable()
+            .unwrap()
+        };
+
+        assert_ne!(
+            icon("https://example.com/a/favicon.ico").src_path(),
+            icon("https://example.com/b/favicon.ico").src_path()
+        );
+    }
+
+    #[test]
+    fn collect_link_previews() {
+        let blocks = [
+            link_preview(
+                "a0000001-0000-4000-8000-000000000001",
+                "https://gamediary.dev/posts/flocking",
+            ),
+            Block {
+                object: "block".to_string(),
+                id: "a0000001-0000-4000-8000-000000000002".parse().unwrap(),
+                created_time: "2022-12-10T15:12:00.000Z".to_string(),
+                last_edited_time: "2022-12-10T15:12:00.000Z".to_string(),
+                has_children: false,
+                archived: false,
+                ty: BlockType::Bookmark {
+                    url: "https://doc.rust-lang.org/book/".to_string(),
+                    caption: link_preview_text("https://gamediary.dev/posts/captioned"),
+                },
+            },
+            Block {
+                object: "block".to_string(),
+                id: "a0000001-0000-4000-8000-000000000003".parse().unwrap(),
+                created_time: "2022-12-10T15:12:00.000Z".to_string(),
+                last_edited_time: "2022-12-10T15:12:00.000Z".to_string(),
+                has_children: true,
+                archived: false,
+                ty: BlockType::Table {
+                    table_width: 2,
+                    has_column_header: false,
+                    has_row_header: false,
+                    children: vec![Block {
+                        object: "block".to_string(),
+                        id: "a0000001-0000-4000-8000-000000000004".parse().unwrap(),
+                        created_time: "2022-12-10T15:12:00.000Z".to_string(),
+                        last_edited_time: "2022-12-10T15:12:00.000Z".to_string(),
+                        has_children: false,
+                        archived: false,
+                        ty: BlockType::TableRow {
+                            cells: vec![
+                                vec![],
+                                link_preview_text("https://gamediary.dev/posts/tabled"),
+                            ],
+                        },
+                    }],
+                },
+            },
+        ];
+
+        let mut urls = HashSet::new();
+        super::collect_link_previews(&blocks, &mut urls);
+        assert_eq!(
+            urls,
+            HashSet::from([
+                "https://gamediary.dev/posts/flocking",
+                "https://doc.rust-lang.org/book/",
+                "https://gamediary.dev/posts/captioned",
+                "https://gamediary.dev/posts/tabled",
+            ])
+        );
+    }
+
+    fn link_preview(id: &str, url: &str) -> Block {
+        Block {
+            object: "block".to_string(),
+            id: id.parse().unwrap(),
+            created_time: "2022-12-10T15:12:00.000Z".to_string(),
+            last_edited_time: "2022-12-10T15:12:00.000Z".to_string(),
+            has_children: false,
+            archived: false,
+            ty: BlockType::Paragraph {
+                text: link_preview_text(url),
+                children: vec![],
+                color: Color::Default,
+            },
+        }
+    }
+
+    fn link_preview_text(url: &str) -> Vec<RichText> {
+        vec![RichText {
+            plain_text: url.to_string(),
+            href: Some(url.to_string()),
+            annotations: Default::default(),
+            ty: RichTextType::Mention {
+                mention: RichTextMentionType::LinkPreview {
+                    url: url.to_string(),
+                },
+            },
+        }]
+    }
+
+    struct FailingFetcher;
+
+    impl Fetcher for FailingFetcher {
+        fn fetch<'a>(&'a self, url: &'a str) -> Pin<Box<dyn Future<Output = Result<String>> + 'a>> {
+            Box::pin(async move { bail!("Tried to fetch {}", url) })
+        }
+    }
+
+    #[tokio::test]
+    async fn unfurl_from_local_server() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = [0; 1024];
+                let read = stream.read(&mut request).await.unwrap();
+                let request = String::from_utf8_lossy(&request[..read]);
+
+                let (content_type, body) = if request.starts_with("GET /page ") {
+                    (
+                        "text/html; charset=utf-8",
+                        r#"<html><head><title>Local page</title><link rel="icon" href="/icon.png"></head></html>"#,
+                    )
+                } else {
+                    ("application/pdf", "%PDF-1.4")
+                };
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    content_type,
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        let page_url = format!("http://{}/page", address);
+        let pdf_url = format!("http://{}/document.pdf", address);
+        let blocks = [
+            link_preview("a0000001-0000-4000-8000-000000000001", &page_url),
+            link_preview("a0000001-0000-4000-8000-000000000002", &pdf_url),
+        ];
+
+        let mut unfurled_links = UnfurledLinks::new();
+        unfurled_links.unfurl_blocks(&Client::new(), &blocks).await;
+
+        let mut expected = UnfurledLinks::new();
+        expected.insert(
+            page_url.clone(),
+            UnfurledLink {
+                title: Some("Local page".to_string()),
+                icon: Some(format!("http://{}/icon.png", address)),
+            },
+        );
+        assert_eq!(unfurled_links, expected);
+
+        let cache = std::env::temp_dir().join(format!(
+            "notion-generator-unfurl-cache-{}.json",
+            address.port()
+        ));
+        unfurled_links.save(&cache).await.unwrap();
+        let mut cached_links = UnfurledLinks::load(&cache).await.unwrap();
+        tokio::fs::remove_file(&cache).await.unwrap();
+        assert_eq!(cached_links, expected);
+
+        // Cached links aren't fetched again
+        cached_links
+            .unfurl_blocks(&FailingFetcher, &blocks[..1])
+            .await;
+        assert_eq!(cached_links, expected);
+
+        assert_eq!(
+            UnfurledLinks::load(&cache).await.unwrap(),
+            UnfurledLinks::new()
+        );
+    }
+}
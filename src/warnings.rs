@@ -0,0 +1,56 @@
+use crate::highlight::language_name;
+use crate::response::{Language, NotionId};
+use std::{fmt, sync::Mutex};
+
+/// Something that didn't stop the page from rendering but made it render differently than in
+/// Notion
+#[derive(Debug, PartialEq, Eq)]
+pub enum Warning {
+    /// A code block was rendered as plain text because its language can't be highlighted
+    UnhighlightedCode { block: NotionId, language: Language },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Warning::UnhighlightedCode { block, language } => write!(
+                f,
+                "Code block {} was rendered as plain text because language {} isn't supported",
+                block,
+                language_name(language).as_deref().unwrap_or("unknown")
+            ),
+        }
+    }
+}
+
+/// A list of warnings emitted while rendering, in the order they were emitted in
+pub struct Warnings {
+    list: Mutex<Vec<Warning>>,
+}
+
+impl Warnings {
+    pub fn new() -> Self {
+        Warnings {
+            list: Mutex::new(Vec::new()),
+        }
+    }
+
+    pub fn push(&self, warning: Warning) {
+        self.list
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .push(warning);
+    }
+
+    pub fn into_vec(self) -> Vec<Warning> {
+        self.list
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for Warnings {
+    fn default() -> Self {
+        Self::new()
+    }
+}